@@ -0,0 +1,96 @@
+//! Timing harness for performance regression tests. It's only compiled with `cargo test --features bench`.
+
+use std::time::{Duration, Instant};
+
+/// It runs `f` `runs` times and returns the median of the elapsed times.\
+/// The median is less sensitive to the noise (other processes, cold caches, ...) than the average.
+pub fn measure<T, F: FnMut() -> T>(runs: usize, mut f: F) -> Duration {
+    let mut times = (0..runs.max(1)).map(|_| {
+        let started_at = Instant::now();
+        std::hint::black_box(f());
+        started_at.elapsed()
+    }).collect::<Vec<Duration>>();
+
+    times.sort();
+
+    times[times.len() / 2]
+}
+
+/// It panics if the median time of `f` exceeds `budget`.
+pub fn assert_within<T, F: FnMut() -> T>(name: &str, runs: usize, budget: Duration, f: F) {
+    let time = measure(runs, f);
+
+    if time > budget {
+        panic!("{name} took {time:?}, which exceeds its budget {budget:?}");
+    }
+}
+
+/// A deterministic pseudo-random number with `len` limbs. The most significant limb is never 0.
+pub fn sample_ubi(len: usize, mut seed: u32) -> crate::UBigInt {
+    crate::utils::rand_ubi(len, &mut seed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{assert_within, measure, sample_ubi};
+    use crate::{BarrettCtx, gcd_binary_ubi, gcd_ubi};
+    use std::time::Duration;
+
+    // The budgets are very generous: they're meant to catch superquadratic regressions, not small slowdowns.
+
+    #[test]
+    fn mul_guard() {
+        let a = sample_ubi(1000, 1);
+        let b = sample_ubi(1000, 2);
+
+        assert_within("1000 limbs * 1000 limbs", 5, Duration::from_secs(2), || a.mul(&b));
+    }
+
+    #[test]
+    fn div_guard() {
+        let a = sample_ubi(2000, 3);
+        let b = sample_ubi(1000, 4);
+
+        assert_within("2000 limbs / 1000 limbs", 5, Duration::from_secs(4), || a.div(&b));
+    }
+
+    // `Ratio` uses `gcd_binary_ubi` to reduce its denominator and numerator.
+    // It doesn't time `Ratio::from_denom_and_numer` directly: in test builds, it checks the result with `gcd_ubi` again.
+    #[test]
+    fn gcd_guard() {
+        let pairs = (0..200).map(
+            |i| (sample_ubi(1 + i % 7, i as u32 * 2 + 5), sample_ubi(1 + i % 5, i as u32 * 2 + 6))
+        ).collect::<Vec<_>>();
+        let big_a = sample_ubi(300, 7);
+        let big_b = sample_ubi(300, 8);
+
+        for (a, b) in pairs.iter() {
+            assert_eq!(gcd_binary_ubi(a, b), gcd_ubi(a, b));
+        }
+
+        assert_eq!(gcd_binary_ubi(&big_a, &big_b), gcd_ubi(&big_a, &big_b));
+
+        assert_within("200 gcds of small numbers", 5, Duration::from_millis(200), || pairs.iter().map(|(a, b)| gcd_binary_ubi(a, b)).collect::<Vec<_>>());
+        assert_within("gcd of 300 limbs", 5, Duration::from_secs(2), || gcd_binary_ubi(&big_a, &big_b));
+    }
+
+    // `pow_mod_ctx` reuses `BarrettCtx` for every exponentiation, so it has to be faster than `pow_mod`.
+    #[test]
+    fn barrett_guard() {
+        let modulus = sample_ubi(16, 9);
+        let ctx = BarrettCtx::new(&modulus);
+        let samples = (0..8).map(
+            |i| (sample_ubi(16, i * 2 + 10), sample_ubi(16, i * 2 + 11))
+        ).collect::<Vec<_>>();
+
+        for (base, exp) in samples.iter() {
+            assert_eq!(base.pow_mod_ctx(exp, &ctx), base.pow_mod(exp, &modulus));
+        }
+
+        let with_ctx = measure(3, || samples.iter().map(|(base, exp)| base.pow_mod_ctx(exp, &ctx)).collect::<Vec<_>>());
+        let without_ctx = measure(3, || samples.iter().map(|(base, exp)| base.pow_mod(exp, &modulus)).collect::<Vec<_>>());
+
+        assert!(with_ctx < without_ctx, "pow_mod_ctx took {with_ctx:?}, but pow_mod took {without_ctx:?}");
+        assert_within("8 exponentiations with BarrettCtx", 3, Duration::from_secs(4), || samples.iter().map(|(base, exp)| base.pow_mod_ctx(exp, &ctx)).collect::<Vec<_>>());
+    }
+}
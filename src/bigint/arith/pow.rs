@@ -8,6 +8,26 @@ impl BigInt {
         BigInt::from_ubi(self.val.pow_u32(exp), self.is_neg() && exp % 2 == 1)
     }
 
+    /// `self.pow_u32(exp)` if the result has at most `max_bits` bits (sign not included). Otherwise, it returns `None`.\
+    /// If `self` has `b` bits, the result has `(b - 1) * exp + 1` ~ `b * exp` bits. If that's obviously too big, it returns `None` without calculating anything.
+    pub fn checked_pow(&self, exp: u32, max_bits: u64) -> Option<Self> {
+        let bits = self.val.bits();
+
+        // lower bound of the bit length (0^n and 1^n are cheap anyway)
+        if bits > 1 && (bits - 1) * exp as u64 + 1 > max_bits {
+            return None;
+        }
+
+        let result = self.pow_u32(exp);
+
+        // it's between the bounds
+        if result.val.bits() > max_bits {
+            return None;
+        }
+
+        Some(result)
+    }
+
     pub fn pow_u32_mut(&mut self, exp: u32) {
         self.val.pow_u32_mut(exp);
         self._is_neg = self.is_neg() && exp % 2 == 1;
@@ -19,3 +39,39 @@ impl BigInt {
         BigInt::from_ubi(UBigInt::pow2(exp), false)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::BigInt;
+
+    #[test]
+    fn checked_pow_test() {
+        // it has to return immediately
+        assert_eq!(BigInt::from_i32(2).checked_pow(1_000_000, 1000), None);
+        assert_eq!(BigInt::from_i32(-3).checked_pow(u32::MAX, 1 << 20), None);
+
+        assert_eq!(BigInt::from_i32(2).checked_pow(999, 1000), Some(BigInt::pow2(999)));
+        assert_eq!(BigInt::from_i32(2).checked_pow(1000, 1000), None);
+        assert_eq!(BigInt::from_i32(-2).checked_pow(3, 4), Some(BigInt::from_i32(-8)));
+        assert_eq!(BigInt::from_i32(-2).checked_pow(3, 3), None);
+
+        // 3^40 has 64 bits, 3^41 has 65 bits
+        assert_eq!(BigInt::from_i32(3).checked_pow(40, 64), Some(BigInt::from_i32(3).pow_u32(40)));
+        assert_eq!(BigInt::from_i32(3).checked_pow(41, 64), None);
+
+        for n in [0, 1, -1] {
+            let n = BigInt::from_i32(n);
+
+            for exp in [0, 1, 2, u32::MAX] {
+                assert_eq!(n.checked_pow(exp, 1), Some(n.pow_u32(exp)));
+            }
+        }
+
+        assert_eq!(BigInt::from_i32(7).checked_pow(0, 1), Some(BigInt::one()));
+        assert_eq!(BigInt::from_i32(7).checked_pow(0, 0), None);
+        assert_eq!(BigInt::one().checked_pow(u32::MAX, 0), None);
+        assert_eq!(BigInt::zero().checked_pow(u32::MAX, 0), Some(BigInt::zero()));
+        assert_eq!(BigInt::from_i32(7).checked_pow(1, 3), Some(BigInt::from_i32(7)));
+        assert_eq!(BigInt::from_i32(7).checked_pow(1, 2), None);
+    }
+}
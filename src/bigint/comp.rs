@@ -206,8 +206,9 @@ impl Ord for BigInt {
 
 #[cfg(test)]
 mod tests {
-    use crate::BigInt;
+    use crate::{BigInt, UBigInt};
     use std::cmp::Ordering;
+    use std::collections::HashSet;
 
     fn bi_comp(bi1: &BigInt, bi2: &BigInt) {
         match bi1.comp(bi2) {
@@ -279,4 +280,41 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn bi_ord_test() {
+        let mut values = [7, -3, 0, -100, 3, 7, i32::MIN + 1].map(BigInt::from_i32).to_vec();
+        values.push(BigInt::from_i64(i64::MAX));
+        values.push(BigInt::from_i64(i64::MIN + 1));
+        values.sort();
+
+        let mut answer = vec![BigInt::from_i64(i64::MIN + 1)];
+        answer.extend([i32::MIN + 1, -100, -3, 0, 3, 7, 7].map(BigInt::from_i32));
+        answer.push(BigInt::from_i64(i64::MAX));
+
+        assert_eq!(values, answer);
+        assert!(BigInt::from_i32(-5) < BigInt::from_i32(2));
+        assert!(BigInt::from_i32(-5) > BigInt::from_i32(-6));
+    }
+
+    #[test]
+    fn bi_hash_test() {
+        let mut set = HashSet::new();
+
+        set.insert(BigInt::zero());
+        set.insert(BigInt::zero().neg());
+        set.insert(BigInt::from_i32(5).sub_i32(5));
+        set.insert(BigInt::from_i32(-5).add_i32(5));
+        assert_eq!(set.len(), 1);
+
+        set.insert(BigInt::from_i32(7));
+        set.insert(BigInt::from_i32(-7));
+        set.insert(BigInt::from_string("-7").unwrap());
+        set.insert(BigInt::from_ubi(UBigInt::from_u32(7), false));
+        assert_eq!(set.len(), 3);
+
+        set.insert(BigInt::from_i64(1 << 40));
+        set.insert(BigInt::exp2(40));
+        assert_eq!(set.len(), 4);
+    }
 }
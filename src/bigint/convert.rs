@@ -6,6 +6,12 @@ use std::str::FromStr;
 mod from;
 mod into;
 
+#[cfg(feature = "num-bigint")]
+mod num;
+
+#[cfg(feature = "serde")]
+mod serde;
+
 impl BigInt {
     pub fn from_i32(n: i32) -> Self {
         let _is_neg = n < 0;
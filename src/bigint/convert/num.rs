@@ -0,0 +1,42 @@
+use crate::{BigInt, UBigInt};
+use num_bigint::Sign;
+
+impl BigInt {
+
+    pub fn to_num_bigint(&self) -> num_bigint::BigInt {
+        let sign = if self.is_neg() { Sign::Minus } else { Sign::Plus };
+
+        num_bigint::BigInt::from_biguint(sign, self.val.to_num_bigint())
+    }
+
+    pub fn from_num_bigint(n: &num_bigint::BigInt) -> Self {
+        BigInt::from_ubi(UBigInt::from_num_bigint(n.magnitude()), n.sign() == Sign::Minus)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::BigInt;
+    use crate::utils::rand_u32;
+
+    #[test]
+    fn num_bigint_test() {
+        for n in [0, 1, -1, i64::MAX, i64::MIN + 1] {
+            assert_eq!(BigInt::from_i64(n).to_num_bigint(), num_bigint::BigInt::from(n));
+            assert_eq!(BigInt::from_num_bigint(&num_bigint::BigInt::from(n)), BigInt::from_i64(n));
+        }
+
+        let mut seed = 53u32;
+
+        for len in 1..40 {
+            let n = BigInt::from_raw((0..len).map(|_| rand_u32(&mut seed) | 1).collect(), seed & 4 == 0);
+            let m = n.to_num_bigint();
+
+            assert_eq!(m.to_string(), n.to_string_dec());
+            assert_eq!(BigInt::from_num_bigint(&m), n);
+
+            let m = m.pow(3) - 1;
+            assert_eq!(BigInt::from_num_bigint(&m), n.pow_u32(3).sub_i32(1));
+        }
+    }
+}
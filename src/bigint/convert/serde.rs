@@ -0,0 +1,44 @@
+use crate::BigInt;
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+/// It's serialized to a decimal string, like `"-12345"`.
+impl Serialize for BigInt {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string_dec())
+    }
+}
+
+/// It accepts the strings that `BigInt::from_string` accepts.
+impl<'de> Deserialize<'de> for BigInt {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+
+        BigInt::from_string(&s).map_err(|e| de::Error::custom(format!("invalid BigInt {s:?}: {e:?}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::BigInt;
+
+    #[test]
+    fn serde_test() {
+        let numbers = [
+            BigInt::zero(),
+            BigInt::from_i32(-12345),
+            BigInt::from_i128(i128::MAX),
+            BigInt::from_i32(-3).pow_u32(201),
+        ];
+
+        for n in numbers.iter() {
+            let json = serde_json::to_string(n).unwrap();
+
+            assert_eq!(json, format!("\"{}\"", n.to_string_dec()));
+            assert_eq!(&serde_json::from_str::<BigInt>(&json).unwrap(), n);
+        }
+
+        assert_eq!(serde_json::from_str::<BigInt>("\"-0x10\"").unwrap(), BigInt::from_i32(-16));
+        assert!(serde_json::from_str::<BigInt>("\"1.5\"").is_err());
+        assert!(serde_json::from_str::<BigInt>("-3").is_err());
+    }
+}
@@ -2,6 +2,8 @@ use crate::{
     BigInt,
     UBigInt,
     gcd_ubi,
+    gcd_binary_ubi,
+    lcm_ubi,
 };
 
 impl BigInt {
@@ -68,6 +70,11 @@ impl BigInt {
         BigInt::from_ubi(UBigInt::factorial(n), false)
     }
 
+    /// It returns `n! / (k! * (n - k)!)`. It returns 0 when `k > n`.
+    pub fn binomial(n: u32, k: u32) -> Self {
+        BigInt::from_ubi(UBigInt::binomial(n, k), false)
+    }
+
     pub fn fibonacci(n: u32) -> Self {
         BigInt::from_ubi(UBigInt::fibonacci(n), false)
     }
@@ -132,3 +139,112 @@ impl BigInt {
 pub fn gcd_bi(a: &BigInt, b: &BigInt) -> BigInt {
     BigInt::from_ubi(gcd_ubi(&a.val, &b.val), false)
 }
+
+// same as `gcd_bi`, but it uses `gcd_binary_ubi`
+pub(crate) fn gcd_binary_bi(a: &BigInt, b: &BigInt) -> BigInt {
+    BigInt::from_ubi(gcd_binary_ubi(&a.val, &b.val), false)
+}
+
+/// The result is never negative. It returns 0 if `a` or `b` is 0.
+pub fn lcm_bi(a: &BigInt, b: &BigInt) -> BigInt {
+    BigInt::from_ubi(lcm_ubi(&a.val, &b.val), false)
+}
+
+/// It returns `(g, x, y)` where `g = gcd(a, b)` and `a * x + b * y = g`. `g` is never negative.
+pub fn gcd_ext_bi(a: &BigInt, b: &BigInt) -> (BigInt, BigInt, BigInt) {
+    let (mut old_r, mut r) = (a.clone(), b.clone());
+    let (mut old_x, mut x) = (BigInt::one(), BigInt::zero());
+    let (mut old_y, mut y) = (BigInt::zero(), BigInt::one());
+
+    while !r.is_zero() {
+        let q = old_r.div(&r);
+
+        let new_r = old_r.sub(&q.mul(&r));
+        old_r = r;
+        r = new_r;
+
+        let new_x = old_x.sub(&q.mul(&x));
+        old_x = x;
+        x = new_x;
+
+        let new_y = old_y.sub(&q.mul(&y));
+        old_y = y;
+        y = new_y;
+    }
+
+    if old_r.is_neg() {
+        old_r.neg_mut();
+        old_x.neg_mut();
+        old_y.neg_mut();
+    }
+
+    (old_r, old_x, old_y)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BigInt, UBigInt, gcd_bi, gcd_ext_bi, lcm_bi};
+
+    #[test]
+    fn factorial_binomial_test() {
+        for n in [0, 1, 5, 20, 21, 128, 129, 300] {
+            let result = BigInt::factorial(n);
+
+            assert_eq!(result, BigInt::from_ubi(UBigInt::factorial(n), false));
+            assert!(!result.is_neg());
+            assert!(result.is_valid());
+        }
+
+        for n in [0, 1, 7, 30, 100] {
+            for k in [0, 1, 3, n / 2, n, n + 1] {
+                let result = BigInt::binomial(n, k);
+
+                assert_eq!(result, BigInt::from_ubi(UBigInt::binomial(n, k), false));
+                assert!(!result.is_neg());
+                assert!(result.is_valid());
+            }
+        }
+
+        assert_eq!(BigInt::binomial(10, 3), BigInt::from_i32(120));
+        assert_eq!(BigInt::binomial(3, 10), BigInt::zero());
+        assert_eq!(
+            BigInt::binomial(200, 100).mul(&BigInt::factorial(100)).mul(&BigInt::factorial(100)),
+            BigInt::factorial(200),
+        );
+    }
+
+    #[test]
+    fn gcd_ext_test() {
+        let samples = [
+            (240, 46), (46, 240), (-240, 46), (240, -46), (-240, -46),
+            (17, 5), (12, 12), (0, 7), (7, 0), (-7, 0), (0, 0), (1, 1),
+            (1_000_000_007, 998_244_353), (2 * 3 * 5 * 7 * 11, 7 * 11 * 13),
+        ];
+
+        for (a, b) in samples.into_iter() {
+            let (a, b) = (BigInt::from_i64(a), BigInt::from_i64(b));
+            let (g, x, y) = gcd_ext_bi(&a, &b);
+
+            assert_eq!(g, gcd_bi(&a, &b));
+            assert_eq!(a.mul(&x).add(&b.mul(&y)), g);
+        }
+
+        let (g, x, y) = gcd_ext_bi(&BigInt::from_i32(240), &BigInt::from_i32(46));
+        assert_eq!((g, x, y), (BigInt::from_i32(2), BigInt::from_i32(-9), BigInt::from_i32(47)));
+
+        let a = BigInt::factorial(30).add_i32(1);
+        let b = BigInt::fibonacci(150).neg();
+        let (g, x, y) = gcd_ext_bi(&a, &b);
+        assert_eq!(g, gcd_bi(&a, &b));
+        assert_eq!(a.mul(&x).add(&b.mul(&y)), g);
+    }
+
+    #[test]
+    fn lcm_test() {
+        assert_eq!(lcm_bi(&BigInt::from_i32(12), &BigInt::from_i32(18)), BigInt::from_i32(36));
+        assert_eq!(lcm_bi(&BigInt::from_i32(-12), &BigInt::from_i32(18)), BigInt::from_i32(36));
+        assert_eq!(lcm_bi(&BigInt::from_i32(-12), &BigInt::from_i32(-18)), BigInt::from_i32(36));
+        assert_eq!(lcm_bi(&BigInt::from_i32(-12), &BigInt::zero()), BigInt::zero());
+        assert!(lcm_bi(&BigInt::from_i32(-12), &BigInt::from_i32(-18)).is_valid());
+    }
+}
@@ -3,6 +3,9 @@ use crate::Ratio;
 #[cfg(test)]
 pub const RUN_ALL_TESTS: bool = true;
 
+/// default value of `UBigInt::mul_threshold()`
+pub const DEFAULT_MUL_THRESHOLD: usize = 64;
+
 /// pre-calculated value of pi. It's equal to `pi_iter(7)`.
 pub fn pi_const() -> Ratio {
     Ratio::from_raw(vec![0, 1996488704, 521357240, 2800251216, 760460394, 6200936], false, vec![793201965, 2280519292, 3213394952, 1450523626, 2315958499, 19480815], false)
@@ -29,3 +29,13 @@ impl From<TryFromIntError> for ConversionError {
         ConversionError::TryFromIntError
     }
 }
+
+/// Errors from `weighted_mean`
+#[derive(Clone, Debug, PartialEq)]
+pub enum WeightedMeanError {
+    /// `values` and `weights` have different lengths
+    LengthMismatch { values: usize, weights: usize },
+
+    /// the sum of `weights` is 0
+    ZeroTotalWeight,
+}
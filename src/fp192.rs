@@ -185,7 +185,7 @@ mod tests {
             assert_eq!(a_f192, rat2.into());
 
             if a > 0 {
-                assert_eq!(a.ilog2(), a_f192.ilog2().try_into().unwrap());
+                assert_eq!(a.ilog2(), u32::try_from(a_f192.ilog2()).unwrap());
 
                 if a < 48 {
                     assert_eq!(F192::from_pow2(a as i64), F192::from(1u64 << a));
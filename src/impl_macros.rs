@@ -63,3 +63,56 @@ macro_rules! impl_trivial_try_from {
         }
     )
 }
+
+// `impl_ops!(T, Add, add, AddAssign, add_assign, add, add_mut; ...)`
+// implements `T + T`, `T + &T`, `&T + T`, `&T + &T`, `T += T` and `T += &T` using `T::add` and `T::add_mut`
+#[macro_export]
+macro_rules! impl_ops {
+    ($t: ty, $($tr: ident, $tr_m: ident, $as_tr: ident, $as_tr_m: ident, $m: ident, $m_mut: ident);+) => ($(
+        impl std::ops::$tr<&$t> for &$t {
+            type Output = $t;
+
+            fn $tr_m(self, other: &$t) -> $t {
+                <$t>::$m(self, other)
+            }
+        }
+
+        impl std::ops::$tr<$t> for &$t {
+            type Output = $t;
+
+            fn $tr_m(self, other: $t) -> $t {
+                <$t>::$m(self, &other)
+            }
+        }
+
+        impl std::ops::$tr<&$t> for $t {
+            type Output = $t;
+
+            fn $tr_m(mut self, other: &$t) -> $t {
+                <$t>::$m_mut(&mut self, other);
+                self
+            }
+        }
+
+        impl std::ops::$tr<$t> for $t {
+            type Output = $t;
+
+            fn $tr_m(mut self, other: $t) -> $t {
+                <$t>::$m_mut(&mut self, &other);
+                self
+            }
+        }
+
+        impl std::ops::$as_tr<&$t> for $t {
+            fn $as_tr_m(&mut self, other: &$t) {
+                <$t>::$m_mut(self, other);
+            }
+        }
+
+        impl std::ops::$as_tr<$t> for $t {
+            fn $as_tr_m(&mut self, other: $t) {
+                <$t>::$m_mut(self, &other);
+            }
+        }
+    )+)
+}
@@ -11,26 +11,34 @@ pub mod utils;
 
 mod impl_macros;
 
-pub use ubigint::{UBigInt, funcs::gcd_ubi};
-pub use bigint::{BigInt, funcs::gcd_bi};
+#[cfg(all(test, feature = "bench"))]
+mod bench;
+
+pub use ubigint::{UBigInt, funcs::BarrettCtx, funcs::gcd_ubi, funcs::gcd_binary_ubi, funcs::lcm_ubi};
+pub use bigint::{BigInt, funcs::gcd_bi, funcs::gcd_ext_bi, funcs::lcm_bi};
 pub use ratio::{
-    Ratio,
+    Ratio, funcs::TaylorSeries,
     funcs::asin_iter, funcs::acos_iter, funcs::atan_iter,
-    funcs::exp_iter, funcs::ln_iter, funcs::pow_iter, funcs::log_iter,
-    funcs::sqrt_iter, funcs::cbrt_iter,
+    funcs::exp_iter, funcs::ln_iter, funcs::pow_iter, funcs::log_iter, funcs::log_base_iter, funcs::log10_iter,
+    funcs::exp_iter_bounded, funcs::exp_m1_iter, funcs::ln_1p_iter,
+    funcs::sqrt_iter, funcs::sqrt_prec, funcs::cbrt_iter,
     funcs::sin_iter, funcs::cos_iter, funcs::tan_iter,
+    funcs::tan_double, funcs::sin_from_tan_half, funcs::cos_from_tan_half,
     funcs::sinh_iter, funcs::cosh_iter, funcs::tanh_iter,
-    funcs::common_denom,
-    e::e_iter, ln2::ln2_iter, pi::pi_iter,
+    funcs::bisect, funcs::common_denom, funcs::common_denominator, funcs::continued_product, funcs::geometric_series_infinite, funcs::powers_of, funcs::smoothstep, funcs::sum_balanced, funcs::weighted_mean,
+    funcs::sqrt_continued_fraction, funcs::sqrt_cf_approx, funcs::solve_pell, funcs::rational_reconstruct,
+    e::e_iter, ln2::ln2_iter, pi::pi_iter, pi::wallis_pi,
     inspect_ieee754_f32, inspect_ieee754_f64
 };
 pub use fp192::F192;
 pub use matrix::{Matrix, MatrixError};
 pub use poly::{
     Polynomial,
+    definite_integral,
     from_points, from_points_generic,
     cubic_2_points, quadratic_3_points, linear_2_points,
+    pade_exp,
 };
 pub use complex::Complex;
-pub use consts::{pi_const, e_const, ln2_const};
-pub use err::ConversionError;
+pub use consts::{pi_const, e_const, ln2_const, DEFAULT_MUL_THRESHOLD};
+pub use err::{ConversionError, WeightedMeanError};
@@ -2,9 +2,15 @@ use crate::Ratio;
 
 pub use err::MatrixError;
 
+mod bareiss;
+mod charpoly;
 mod det;
+mod det_mod;
 mod err;
+mod exp;
 mod inv;
+mod lu;
+mod solve;
 mod utils;
 
 /// It's very naively implemented, thus very slow.
@@ -65,6 +71,12 @@ impl Matrix {
         }
     }
 
+    /// Coefficient matrix of a polynomial interpolation: `result.get(i, j) = xs[i]^(degree - j)`.\
+    /// If `[c0, c1, ...]` is the solution of `result * [c0, c1, ...] = ys`, `c0 * x^degree + c1 * x^(degree - 1) + ...` passes `(xs[i], ys[i])`.
+    pub fn interpolation_system(xs: &[Ratio], degree: usize) -> Self {
+        Matrix::generate(degree + 1, xs.len(), |i, j| xs[i].pow_i32((degree - j) as i32))
+    }
+
     pub fn empty() -> Self {
         Matrix { data: vec![], cols: 0, rows: 0 }
     }
@@ -178,6 +190,25 @@ impl Matrix {
         }
     }
 
+    /// result.get(i, j) = f(self.get(i, j))
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn map(&self, f: impl Fn(&Ratio) -> Ratio) -> Self {
+        Matrix::generate(self.cols, self.rows, |i, j| f(self.get(i, j)))
+    }
+
+    /// result.get(i, j) = f(self.get(i, j), other.get(i, j))
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn zip_with(&self, other: &Matrix, f: impl Fn(&Ratio, &Ratio) -> Ratio) -> Result<Self, MatrixError> {
+        if (self.cols, self.rows) != (other.cols, other.rows) {
+            return Err(MatrixError::WrongDimension {
+                expected: (self.cols, self.rows),
+                got: (other.cols, other.rows),
+            });
+        }
+
+        Ok(Matrix::generate(self.cols, self.rows, |i, j| f(self.get(i, j), other.get(i, j))))
+    }
+
     #[must_use = "method returns a new number and does not mutate the original value"]
     pub fn mul(&self, other: &Matrix) -> Result<Self, MatrixError> {
         if self.cols != other.rows {
@@ -200,6 +231,21 @@ impl Matrix {
         Ok(result)
     }
 
+    /// sum of the diagonal elements
+    pub fn trace(&self) -> Result<Ratio, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare(self.cols, self.rows));
+        }
+
+        let mut result = Ratio::zero();
+
+        for i in 0..self.rows {
+            result.add_mut(self.get(i, i));
+        }
+
+        Ok(result)
+    }
+
     #[must_use = "method returns a new number and does not mutate the original value"]
     pub fn transpose(&self) -> Self {
         let result = (0..self.cols).map(
@@ -246,7 +292,7 @@ impl Matrix {
 
 #[cfg(test)]
 mod tests {
-    use crate::Matrix;
+    use crate::{Matrix, MatrixError, Ratio};
 
     #[test]
     fn basic_test() {
@@ -297,4 +343,78 @@ mod tests {
             vec![6, 58, 47, 83],
         ]).unwrap());
     }
+
+    #[test]
+    fn map_test() {
+        let mat0 = Matrix::from_vec_generic(vec![
+            vec![7, -3, 3],
+            vec![2, 9, -1],
+        ]).unwrap();
+
+        let mat1 = Matrix::from_vec_generic(vec![
+            vec![1, 3, 6],
+            vec![0, -7, 2],
+        ]).unwrap();
+
+        assert_eq!(mat0.map(|n| n.neg()), mat0.mul_k(-1));
+        assert_eq!(mat0.map(|n| n.neg()).map(|n| n.neg()), mat0);
+        assert_eq!(mat0.map(|n| n.abs()), Matrix::from_vec_generic(vec![
+            vec![7, 3, 3],
+            vec![2, 9, 1],
+        ]).unwrap());
+
+        assert_eq!(mat0.zip_with(&mat1, |a, b| a.add(b)), mat0.add(&mat1));
+        assert_eq!(mat0.zip_with(&mat1, |a, b| a.sub(b)).unwrap(), mat0.add(&mat1.mul_k(-1)).unwrap());
+        assert_eq!(
+            mat0.zip_with(&mat0.transpose(), |a, _| a.clone()),
+            Err(MatrixError::WrongDimension { expected: (3, 2), got: (2, 3) }),
+        );
+
+        assert_eq!(Matrix::empty().map(|_| Ratio::one()), Matrix::empty());
+    }
+
+    #[test]
+    fn transpose_test() {
+        // 4 rows, 3 columns
+        let a = Matrix::from_vec_generic(vec![
+            vec![7, -3, 3],
+            vec![2, 9, -1],
+            vec![5, 5, 3],
+            vec![6, 0, 1],
+        ]).unwrap();
+
+        // 3 rows, 2 columns
+        let b = Matrix::generate(2, 3, |i, j| Ratio::from_denom_and_numer_i32((i + j + 1) as i32, i as i32 - j as i32));
+
+        let at = a.transpose();
+        assert_eq!((at.rows, at.cols), (3, 4));
+        assert_eq!(at, Matrix::from_vec_generic(vec![
+            vec![7, 2, 5, 6],
+            vec![-3, 9, 5, 0],
+            vec![3, -1, 3, 1],
+        ]).unwrap());
+
+        for i in 0..a.rows {
+            for j in 0..a.cols {
+                assert_eq!(a.get(i, j), at.get(j, i));
+            }
+        }
+
+        assert_eq!(at.transpose(), a);
+        assert_eq!(b.transpose().transpose(), b);
+
+        // (AB)^T = B^T A^T
+        assert_eq!(a.mul(&b).unwrap().transpose(), b.transpose().mul(&a.transpose()).unwrap());
+
+        // A^T A is symmetric
+        let ata = at.mul(&a).unwrap();
+        assert_eq!(ata.transpose(), ata);
+
+        let mut c = b.clone();
+        c.transpose_mut();
+        assert_eq!(c, b.transpose());
+
+        assert_eq!(Matrix::identity(5).transpose(), Matrix::identity(5));
+        assert_eq!(Matrix::empty().transpose(), Matrix::empty());
+    }
 }
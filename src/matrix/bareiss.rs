@@ -0,0 +1,121 @@
+use crate::{BigInt, Matrix, MatrixError, Ratio, common_denominator};
+
+impl Matrix {
+
+    /// It computes the determinant with the Bareiss algorithm, which is a fraction-free Gaussian elimination.\
+    /// It first scales each row to integers, then every intermediate value is an integer (a minor of the scaled matrix), so it doesn't suffer from the denominator blowup.
+    pub fn determinant_bareiss(&self) -> Result<Ratio, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare(self.cols, self.rows));
+        }
+
+        let n = self.cols;
+
+        if n == 0 {
+            return Err(MatrixError::EmptyMatrix);
+        }
+
+        // det(self) = det(m) / (d_0 * d_1 * ... * d_(n-1))
+        let mut scale = BigInt::one();
+        let mut m = Vec::with_capacity(n);
+
+        for row in self.data.iter() {
+            let (d, numers) = common_denominator(row);
+            scale.mul_mut(&d);
+            m.push(numers);
+        }
+
+        let mut prev_pivot = BigInt::one();
+        let mut negate = false;
+
+        for k in 0..(n - 1) {
+            if m[k][k].is_zero() {
+                match ((k + 1)..n).find(|i| !m[*i][k].is_zero()) {
+                    Some(i) => {
+                        m.swap(k, i);
+                        negate = !negate;
+                    },
+                    None => { return Ok(Ratio::zero()); },
+                }
+            }
+
+            for i in (k + 1)..n {
+                for j in (k + 1)..n {
+                    // the division is always exact
+                    m[i][j] = m[i][j].mul(&m[k][k]).sub(&m[i][k].mul(&m[k][j])).div(&prev_pivot);
+                }
+            }
+
+            prev_pivot = m[k][k].clone();
+        }
+
+        let mut det = m[n - 1][n - 1].clone();
+
+        if negate {
+            det.neg_mut();
+        }
+
+        Ok(Ratio::from_denom_and_numer(scale, det))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix, MatrixError, Ratio};
+    use crate::utils::rand_u32;
+
+    #[test]
+    fn bareiss_test() {
+        let mat = Matrix::from_vec_generic(vec![
+            vec![2, -3, 1],
+            vec![2, 0, -1],
+            vec![1, 4, 5],
+        ]).unwrap();
+
+        assert_eq!(mat.determinant_bareiss(), Ok(Ratio::from_i32(49)));
+
+        let mut seed = 0x5eed_1234u32;
+        let mut next = || rand_u32(&mut seed) >> 16;
+
+        for n in 1..7 {
+            for _ in 0..12 {
+                // small entries, so that singular matrices are common
+                let numers = (0..(n * n)).map(|_| (next() % 5) as i32 - 2).collect::<Vec<_>>();
+                let denoms = (0..(n * n)).map(|_| (next() % 3) as i32 + 1).collect::<Vec<_>>();
+                let mat = Matrix::generate(n, n, |i, j| Ratio::from_denom_and_numer_i32(denoms[i * n + j], numers[i * n + j]));
+                let det = mat.determinant_bareiss().unwrap();
+
+                assert_eq!(det, mat.determinant_lu());
+
+                // `determinant_cofactor` doesn't work with 1 by 1 matrices
+                if 1 < n && n < 6 {
+                    assert_eq!(det, mat.determinant_cofactor());
+                }
+
+                // `inverse` fails exactly when the determinant is 0
+                match mat.inverse() {
+                    Ok(inv) => {
+                        assert!(!det.is_zero());
+                        assert_eq!(inv.determinant_bareiss().unwrap(), det.reci());
+                    },
+                    Err(e) => {
+                        assert_eq!(e, MatrixError::ZeroDeterminant);
+                        assert!(det.is_zero());
+                    },
+                }
+            }
+        }
+
+        // it has to swap the rows
+        let mat = Matrix::from_vec_generic(vec![
+            vec![0, 1, 2],
+            vec![0, 3, 4],
+            vec![5, 6, 7],
+        ]).unwrap();
+
+        assert_eq!(mat.determinant_bareiss(), Ok(Ratio::from_i32(-10)));
+
+        assert_eq!(Matrix::zeros(2, 3).determinant_bareiss(), Err(MatrixError::NotSquare(2, 3)));
+        assert_eq!(Matrix::empty().determinant_bareiss(), Err(MatrixError::EmptyMatrix));
+    }
+}
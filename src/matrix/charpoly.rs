@@ -0,0 +1,133 @@
+use crate::{Matrix, MatrixError, Polynomial, Ratio};
+
+impl Matrix {
+
+    /// It returns `det(xI - self)`, whose roots are the eigenvalues of `self`.\
+    /// It uses the Faddeev-LeVerrier algorithm, which only needs matrix multiplications and traces.
+    pub fn characteristic_polynomial(&self) -> Result<Polynomial, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare(self.cols, self.rows));
+        }
+
+        let n = self.cols;
+
+        if n == 0 {
+            return Err(MatrixError::EmptyMatrix);
+        }
+
+        // coeffs[k] is the coefficient of x^(n - k)
+        let mut coeffs = Vec::with_capacity(n + 1);
+        coeffs.push(Ratio::one());
+
+        // M_0 = 0, M_k = A * M_(k-1) + c_(n-k+1) * I
+        // c_(n-k) = -tr(A * M_k) / k
+        let mut m = Matrix::zeros(n, n);
+
+        for k in 1..(n + 1) {
+            // Safety: `self` and `m` are n by n matrices
+            let mut am = self.mul(&m).unwrap();
+
+            for i in 0..n {
+                am.get_mut(i, i).add_mut(&coeffs[k - 1]);
+            }
+
+            m = am;
+            let c = self.mul(&m).unwrap().trace().unwrap().div_i32(-(k as i32));
+            coeffs.push(c);
+        }
+
+        Ok(Polynomial::from_vec(coeffs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix, MatrixError, Polynomial, Ratio};
+
+    // p(mat), using Horner's method
+    fn calc_matrix(p: &Polynomial, mat: &Matrix) -> Matrix {
+        let n = mat.cols;
+        let mut result = Matrix::zeros(n, n);
+
+        for coeff in p.to_vec().iter() {
+            result = result.mul(mat).unwrap();
+            result.add_mut(&Matrix::identity(n).mul_k(coeff.clone())).unwrap();
+        }
+
+        result
+    }
+
+    #[test]
+    fn characteristic_polynomial_test() {
+        // (x - 2)(x - 3)(x + 1) = x^3 - 4x^2 + x + 6
+        let diag = Matrix::from_vec_generic(vec![
+            vec![2, 0, 0],
+            vec![0, 3, 0],
+            vec![0, 0, -1],
+        ]).unwrap();
+
+        assert_eq!(
+            diag.characteristic_polynomial().unwrap(),
+            Polynomial::from_vec(vec![1, -4, 1, 6].into_iter().map(Ratio::from_i32).collect()),
+        );
+
+        for d in [2, 3, -1] {
+            assert!(diag.characteristic_polynomial().unwrap().calc(&Ratio::from_i32(d)).is_zero());
+        }
+
+        // x^2 - 5x - 2
+        let mat = Matrix::from_vec_generic(vec![
+            vec![1, 2],
+            vec![3, 4],
+        ]).unwrap();
+
+        assert_eq!(
+            mat.characteristic_polynomial().unwrap(),
+            Polynomial::from_vec(vec![1, -5, -2].into_iter().map(Ratio::from_i32).collect()),
+        );
+
+        let samples = [
+            mat,
+            diag,
+            Matrix::from_vec_generic(vec![
+                vec![7, 3, 3, 1],
+                vec![2, 9, 1, -4],
+                vec![5, 5, 3, 0],
+                vec![6, -5, 1, 2],
+            ]).unwrap(),
+            Matrix::generate(6, 6, |i, j| Ratio::from_denom_and_numer_i32((i + j + 1) as i32, 1)),
+        ];
+
+        for mat in samples.iter() {
+            let p = mat.characteristic_polynomial().unwrap();
+
+            // Cayley-Hamilton theorem
+            assert_eq!(calc_matrix(&p, mat), Matrix::zeros(mat.cols, mat.rows));
+
+            // the constant term is (-1)^n * det(A)
+            let mut det = mat.determinant().unwrap();
+
+            if mat.cols % 2 == 1 {
+                det.neg_mut();
+            }
+
+            assert_eq!(&p.calc(&Ratio::zero()), &det);
+        }
+
+        assert_eq!(Matrix::zeros(2, 3).characteristic_polynomial(), Err(MatrixError::NotSquare(2, 3)));
+        assert_eq!(Matrix::empty().characteristic_polynomial(), Err(MatrixError::EmptyMatrix));
+    }
+
+    #[test]
+    fn trace_test() {
+        let mat = Matrix::from_vec_generic(vec![
+            vec![7, 3, 3],
+            vec![2, 9, 1],
+            vec![5, 5, -3],
+        ]).unwrap();
+
+        assert_eq!(mat.trace(), Ok(Ratio::from_i32(13)));
+        assert_eq!(Matrix::identity(5).trace(), Ok(Ratio::from_i32(5)));
+        assert_eq!(Matrix::zeros(2, 3).trace(), Err(MatrixError::NotSquare(2, 3)));
+    }
+}
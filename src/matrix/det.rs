@@ -77,14 +77,20 @@ impl Matrix {
                 }
             }
         } else {
-            let mut result = Ratio::zero();
+            self.determinant_bareiss()
+        }
+    }
 
-            for j in 0..self.cols {
-                result.add_mut(&self.get(0, j).mul(&self.cofactor(0, j)));
-            }
+    // O(n!), only for testing `determinant_lu` and `determinant_bareiss`
+    #[cfg(test)]
+    pub(crate) fn determinant_cofactor(&self) -> Ratio {
+        let mut result = Ratio::zero();
 
-            Ok(result)
+        for j in 0..self.cols {
+            result.add_mut(&self.get(0, j).mul(&self.cofactor(0, j)));
         }
+
+        result
     }
 
     /// It's your responsibility to make sure that the matrix is 2 by 2.
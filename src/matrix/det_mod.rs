@@ -0,0 +1,153 @@
+use crate::{BigInt, Matrix, MatrixError, Ratio, UBigInt};
+
+impl Matrix {
+
+    /// It returns `det(self) % p`, where `p` is a prime number. The result is in range `0..p`.\
+    /// It uses Gaussian elimination over the integers modulo `p`, so the numbers never get bigger than `p^2`.\
+    /// A ratio `a / b` is treated as `a * b^-1 (mod p)`. It panics when `p` is 0 or when a denominator is a multiple of `p`.\
+    /// If `p` is not a prime number, it may panic or return a wrong result.
+    pub fn determinant_mod(&self, p: &UBigInt) -> Result<UBigInt, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare(self.cols, self.rows));
+        }
+
+        let n = self.cols;
+
+        if n == 0 {
+            return Err(MatrixError::EmptyMatrix);
+        }
+
+        if p.is_zero() {
+            panic!("Attempt to divide by zero: determinant_mod(0)");
+        }
+
+        let mut m = self.data.iter().map(
+            |row| row.iter().map(|r| ratio_mod(r, p)).collect::<Vec<UBigInt>>()
+        ).collect::<Vec<_>>();
+        let mut result = UBigInt::one().rem(p);
+
+        for k in 0..n {
+            let pivot = match (k..n).find(|i| !m[*i][k].is_zero()) {
+                Some(i) => i,
+                None => { return Ok(UBigInt::zero()); },
+            };
+
+            // swapping two rows negates the determinant
+            if pivot != k {
+                m.swap(k, pivot);
+                result = p.sub(&result).rem(p);
+            }
+
+            result = result.mul(&m[k][k]).rem(p);
+            let pivot_inv = m[k][k].mod_inv(p).expect("`p` is not a prime number");
+            let pivot_row = m[k].clone();
+
+            for row in m[(k + 1)..].iter_mut() {
+                if row[k].is_zero() {
+                    continue;
+                }
+
+                let factor = row[k].mul(&pivot_inv).rem(p);
+
+                // row -= factor * pivot_row
+                for (curr, pivot_curr) in row[k..].iter_mut().zip(pivot_row[k..].iter()) {
+                    let sub = factor.mul(pivot_curr).rem(p);
+                    *curr = curr.add(&p.sub(&sub)).rem(p);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+// `n % p` in range `0..p`
+fn ratio_mod(n: &Ratio, p: &UBigInt) -> UBigInt {
+    let p_bi = BigInt::from_ubi(p.clone(), false);
+    let bi_mod = |n: &BigInt| {
+        let mut result = n.rem(&p_bi);
+
+        if result.is_neg() {
+            result.add_mut(&p_bi);
+        }
+
+        // Safety: `result` is not negative
+        result.to_ubi().unwrap()
+    };
+
+    let numer = bi_mod(&n.get_numer());
+    let denom = bi_mod(&n.get_denom());
+
+    if denom.is_zero() {
+        panic!("Attempt to divide by zero: the denominator of {n:?} is a multiple of {p:?}");
+    }
+
+    numer.mul(&denom.mod_inv(p).expect("`p` is not a prime number")).rem(p)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BigInt, Matrix, MatrixError, Ratio, UBigInt};
+    use crate::utils::rand_u32;
+
+    #[test]
+    fn determinant_mod_test() {
+        let primes = [
+            UBigInt::from_u32(2),
+            UBigInt::from_u32(7),
+            UBigInt::from_u32(1_000_000_007),
+            UBigInt::from_u64((1 << 61) - 1),
+            UBigInt::from_u32(2).pow_u32(127).sub_u32(1),
+        ];
+        let mut seed = 0x0bad_cafeu32;
+        let mut next = || rand_u32(&mut seed) >> 8;
+
+        for n in 1..7 {
+            for _ in 0..8 {
+                let entries = (0..(n * n)).map(|_| (next() % 2001) as i32 - 1000).collect::<Vec<_>>();
+                let mat = Matrix::generate(n, n, |i, j| Ratio::from_i32(entries[i * n + j]));
+                let det = mat.determinant().unwrap();
+
+                for p in primes.iter() {
+                    let p_bi = BigInt::from_ubi(p.clone(), false);
+                    let mut answer = det.get_numer().rem(&p_bi);
+
+                    if answer.is_neg() {
+                        answer.add_mut(&p_bi);
+                    }
+
+                    assert_eq!(mat.determinant_mod(p).unwrap(), answer.to_ubi().unwrap());
+                }
+            }
+        }
+
+        // a / b -> a * b^-1
+        let mat = Matrix::from_vec(vec![
+            vec![Ratio::from_denom_and_numer_i32(2, 1), Ratio::from_i32(3)],
+            vec![Ratio::from_i32(-1), Ratio::from_denom_and_numer_i32(3, 4)],
+        ]).unwrap();
+
+        // det = 1/2 * 4/3 + 3 = 11/3, and 3 * 8 = 11 (mod 13)
+        assert_eq!(mat.determinant().unwrap(), Ratio::from_denom_and_numer_i32(3, 11));
+        assert_eq!(mat.determinant_mod(&UBigInt::from_u32(13)).unwrap(), UBigInt::from_u32(8));
+
+        // singular mod 5, but not over the rationals
+        let mat = Matrix::from_vec_generic(vec![
+            vec![1, 2],
+            vec![3, 11],
+        ]).unwrap();
+
+        assert_eq!(mat.determinant_mod(&UBigInt::from_u32(5)).unwrap(), UBigInt::zero());
+        assert_eq!(mat.determinant_mod(&UBigInt::from_u32(7)).unwrap(), UBigInt::from_u32(5));
+
+        assert_eq!(Matrix::zeros(2, 3).determinant_mod(&UBigInt::from_u32(7)), Err(MatrixError::NotSquare(2, 3)));
+        assert_eq!(Matrix::empty().determinant_mod(&UBigInt::from_u32(7)), Err(MatrixError::EmptyMatrix));
+    }
+
+    #[test]
+    #[should_panic]
+    fn determinant_mod_denom_test() {
+        let mat = Matrix::from_vec(vec![vec![Ratio::from_denom_and_numer_i32(7, 1)]]).unwrap();
+        let _ = mat.determinant_mod(&UBigInt::from_u32(7));
+    }
+}
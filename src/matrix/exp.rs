@@ -0,0 +1,74 @@
+use crate::{Matrix, MatrixError, Ratio};
+
+impl Matrix {
+
+    /// It returns `e^self = sigma{k=0}{iter} self^k / k!`. It gets more accurate as `iter` gets bigger.\
+    /// Every element is an exact `Ratio`, so the denominators grow very fast.
+    pub fn exp_iter(&self, iter: usize) -> Result<Matrix, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare(self.cols, self.rows));
+        }
+
+        let mut result = Matrix::identity(self.cols);
+        let mut term = Matrix::identity(self.cols);
+
+        for k in 1..(iter as i32 + 1) {
+            // Safety: `self` and `term` are square matrices of the same size
+            term = term.mul(self).unwrap();
+            term.mul_k_mut(Ratio::from_denom_and_numer_i32(k, 1));
+            result.add_mut(&term).unwrap();
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix, MatrixError, Ratio, exp_iter, exp_m1_iter};
+    use crate::utils::are_close;
+
+    #[test]
+    fn matrix_exp_test() {
+        for n in [1, 2, 5] {
+            assert_eq!(Matrix::zeros(n, n).exp_iter(8), Ok(Matrix::identity(n)));
+            assert_eq!(Matrix::identity(n).exp_iter(0), Ok(Matrix::identity(n)));
+        }
+
+        let diag = ["1", "-0.5", "0", "0.25", "-0.125", "1.5"].map(|n| Ratio::from_string(n).unwrap());
+        let mat = Matrix::generate(6, 6, |i, j| if i == j { diag[i].clone() } else { Ratio::zero() });
+        let exp = mat.exp_iter(16).unwrap();
+
+        for (i, d) in diag.iter().enumerate() {
+            for j in 0..6 {
+                if i != j {
+                    assert!(exp.get(i, j).is_zero());
+                }
+            }
+
+            assert!(are_close(exp.get(i, i), &exp_iter(d, 16), 1e-8));
+
+            // `exp_m1_iter` uses the same truncated series for small numbers
+            if d.abs().lt(&Ratio::from_denom_and_numer_i32(2, 1)) {
+                assert_eq!(exp.get(i, i), &exp_m1_iter(d, 16).add_i32(1));
+            }
+        }
+
+        // nilpotent: e^N = I + N + N^2 / 2
+        let nil = Matrix::from_vec_generic(vec![
+            vec![0, 1, 2],
+            vec![0, 0, 3],
+            vec![0, 0, 0],
+        ]).unwrap();
+        let expected = Matrix::from_vec(vec![
+            vec![Ratio::one(), Ratio::one(), Ratio::from_denom_and_numer_i32(2, 7)],
+            vec![Ratio::zero(), Ratio::one(), Ratio::from_i32(3)],
+            vec![Ratio::zero(), Ratio::zero(), Ratio::one()],
+        ]).unwrap();
+
+        assert_eq!(nil.exp_iter(2), Ok(expected.clone()));
+        assert_eq!(nil.exp_iter(10), Ok(expected));
+
+        assert_eq!(Matrix::zeros(2, 3).exp_iter(4), Err(MatrixError::NotSquare(2, 3)));
+    }
+}
@@ -21,8 +21,10 @@ impl Matrix {
         let n = self.cols;
 
         if n < 5 {
-            if n < 2 {
+            if n == 0 {
                 self.clone()
+            } else if n == 1 {
+                Matrix::identity(1)
             } else if n == 2 {
                 Matrix::from_raw(
                     vec![
@@ -0,0 +1,174 @@
+use crate::{Matrix, MatrixError, Ratio};
+
+impl Matrix {
+    /// It returns `(L, U, P)` where `PA = LU`.\
+    /// `L` is a lower triangular matrix whose diagonal elements are all 1, and `U` is an upper triangular matrix.\
+    /// `P` is a permutation: the `i`th row of `PA` is the `P[i]`th row of `A`.\
+    /// It also works for singular matrices. In that case, some diagonal elements of `U` are 0.
+    pub fn lu_decompose(&self) -> Result<(Matrix, Matrix, Vec<usize>), MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare(self.cols, self.rows));
+        }
+
+        let n = self.cols;
+
+        if n == 0 {
+            return Err(MatrixError::EmptyMatrix);
+        }
+
+        let mut l = Matrix::identity(n);
+        let mut u = self.clone();
+        let mut p = (0..n).collect::<Vec<usize>>();
+
+        for k in 0..n {
+            let pivot = match (k..n).find(|i| !u.get(*i, k).is_zero()) {
+                Some(i) => i,
+
+                // all the elements below `u[k][k]` are already 0
+                None => { continue; }
+            };
+
+            if pivot != k {
+                u.data.swap(pivot, k);
+                p.swap(pivot, k);
+
+                for j in 0..k {
+                    let tmp = l.get(pivot, j).clone();
+                    *l.get_mut(pivot, j) = l.get(k, j).clone();
+                    *l.get_mut(k, j) = tmp;
+                }
+            }
+
+            let pivot_reci = u.get(k, k).reci();
+
+            for i in (k + 1)..n {
+                if u.get(i, k).is_zero() {
+                    continue;
+                }
+
+                let coeff = u.get(i, k).mul(&pivot_reci);
+
+                for j in (k + 1)..n {
+                    let sub = coeff.mul(u.get(k, j));
+                    u.get_mut(i, j).sub_mut(&sub);
+                }
+
+                *u.get_mut(i, k) = Ratio::zero();
+                *l.get_mut(i, k) = coeff;
+            }
+        }
+
+        Ok((l, u, p))
+    }
+
+    // O(n^3), only for testing `determinant_bareiss`
+    // `self` must be a non-empty square matrix
+    #[cfg(test)]
+    pub(crate) fn determinant_lu(&self) -> Ratio {
+        let (_, u, p) = self.lu_decompose().unwrap();
+        let mut result = Ratio::one();
+
+        for i in 0..self.cols {
+            result.mul_mut(u.get(i, i));
+        }
+
+        if permutation_is_odd(&p) {
+            result.neg_mut();
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+fn permutation_is_odd(p: &[usize]) -> bool {
+    let mut visited = vec![false; p.len()];
+    let mut is_odd = false;
+
+    for start in 0..p.len() {
+        if visited[start] {
+            continue;
+        }
+
+        let mut curr = start;
+        let mut cycle_len = 0;
+
+        while !visited[curr] {
+            visited[curr] = true;
+            curr = p[curr];
+            cycle_len += 1;
+        }
+
+        // a cycle of length k is (k - 1) transpositions
+        if cycle_len % 2 == 0 {
+            is_odd = !is_odd;
+        }
+    }
+
+    is_odd
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix, MatrixError, Ratio};
+
+    #[test]
+    fn lu_test() {
+        let samples = vec![
+            Matrix::from_vec_generic(vec![
+                vec![0, 2, 1],
+                vec![1, 1, 0],
+                vec![2, 1, 3],
+            ]).unwrap(),
+            Matrix::from_vec_generic(vec![
+                vec![1, 2, 3],
+                vec![2, 4, 6],
+                vec![1, 0, 1],
+            ]).unwrap(),
+            Matrix::generate(6, 6, |i, j| Ratio::from_denom_and_numer_i32((i + 1) as i32, ((i * 7 + j * 3) % 5) as i32 - 2)),
+            Matrix::generate(5, 5, |i, j| ((i * j + i + 2 * j) % 4).into()),
+            Matrix::zeros(4, 4),
+        ];
+
+        for mat in samples.into_iter() {
+            let n = mat.cols;
+            let (l, u, p) = mat.lu_decompose().unwrap();
+            let pa = Matrix::generate(n, n, |i, j| mat.get(p[i], j).clone());
+
+            assert_eq!(pa, l.mul(&u).unwrap());
+
+            for i in 0..n {
+                assert!(l.get(i, i).is_one());
+
+                for j in (i + 1)..n {
+                    assert!(l.get(i, j).is_zero());
+                    assert!(u.get(j, i).is_zero());
+                }
+            }
+
+            if n > 4 {
+                assert_eq!(mat.determinant_lu(), mat.determinant_cofactor());
+            }
+
+            assert_eq!(mat.determinant_lu(), mat.determinant().unwrap());
+        }
+
+        assert_eq!(Matrix::empty().lu_decompose(), Err(MatrixError::EmptyMatrix));
+        assert_eq!(Matrix::zeros(3, 2).lu_decompose(), Err(MatrixError::NotSquare(3, 2)));
+    }
+
+    #[test]
+    fn large_determinant_test() {
+        // det(lower * upper) = product of the diagonal elements of `lower`
+        let lower = Matrix::generate(10, 10, |i, j| if i < j { 0.into() } else if i == j { ((i + 2) as i32).into() } else { (((i * 3 + j) % 7) as i32 - 3).into() });
+        let upper = Matrix::generate(10, 10, |i, j| if i > j { 0.into() } else if i == j { 1.into() } else { (((i + j * 5) % 9) as i32 - 4).into() });
+        let mut mat = lower.mul(&upper).unwrap();
+        let det = (2..12).fold(Ratio::one(), |acc, n| acc.mul_i32(n));
+
+        assert_eq!(mat.determinant().unwrap(), det);
+
+        // swapping two rows negates the determinant
+        mat.data.swap(0, 7);
+        assert_eq!(mat.determinant().unwrap(), det.neg());
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{Matrix, MatrixError, Ratio};
+
+impl Matrix {
+
+    /// It returns `x` where `self * x = b`.\
+    /// `self` has to be a non-singular square matrix, and `b` may have multiple columns.\
+    /// It's Gaussian elimination with row swaps. Since every operation is exact, any non-zero pivot works.\
+    /// It's much cheaper than `self.inverse()?.mul(b)`.
+    pub fn solve(&self, b: &Matrix) -> Result<Self, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare(self.cols, self.rows));
+        }
+
+        let n = self.cols;
+
+        if n == 0 {
+            return Err(MatrixError::EmptyMatrix);
+        }
+
+        if b.rows != n {
+            return Err(MatrixError::WrongDimension {
+                expected: (b.cols, n),
+                got: (b.cols, b.rows),
+            });
+        }
+
+        let mut a = self.clone();
+        let mut x = b.clone();
+
+        for k in 0..n {
+            let pivot = match (k..n).find(|i| !a.get(*i, k).is_zero()) {
+                Some(i) => i,
+                None => { return Err(MatrixError::ZeroDeterminant); }
+            };
+
+            if pivot != k {
+                a.data.swap(pivot, k);
+                x.data.swap(pivot, k);
+            }
+
+            let pivot_reci = a.get(k, k).reci();
+
+            for i in (k + 1)..n {
+                if a.get(i, k).is_zero() {
+                    continue;
+                }
+
+                let coeff = a.get(i, k).mul(&pivot_reci);
+
+                for j in (k + 1)..n {
+                    let sub = coeff.mul(a.get(k, j));
+                    a.get_mut(i, j).sub_mut(&sub);
+                }
+
+                for j in 0..x.cols {
+                    let sub = coeff.mul(x.get(k, j));
+                    x.get_mut(i, j).sub_mut(&sub);
+                }
+
+                *a.get_mut(i, k) = Ratio::zero();
+            }
+        }
+
+        // back substitution
+        for k in (0..n).rev() {
+            let pivot_reci = a.get(k, k).reci();
+
+            for j in 0..x.cols {
+                let mut curr = x.get(k, j).clone();
+
+                for i in (k + 1)..n {
+                    curr.sub_mut(&a.get(k, i).mul(x.get(i, j)));
+                }
+
+                *x.get_mut(k, j) = curr.mul(&pivot_reci);
+            }
+        }
+
+        Ok(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix, MatrixError, Ratio};
+
+    #[test]
+    fn solve_test() {
+        // 2x + y - z = 8
+        // -3x - y + 2z = -11
+        // -2x + y + 2z = -3
+        let a = Matrix::from_vec_generic(vec![
+            vec![2, 1, -1],
+            vec![-3, -1, 2],
+            vec![-2, 1, 2],
+        ]).unwrap();
+        let b = Matrix::from_vec_generic(vec![vec![8], vec![-11], vec![-3]]).unwrap();
+
+        assert_eq!(
+            a.solve(&b).unwrap(),
+            Matrix::from_vec_generic(vec![vec![2], vec![3], vec![-1]]).unwrap(),
+        );
+
+        let samples = vec![
+            // the first pivot is 0
+            Matrix::from_vec_generic(vec![
+                vec![0, 2, 1],
+                vec![1, 1, 0],
+                vec![2, 1, 3],
+            ]).unwrap(),
+            Matrix::generate(6, 6, |i, j| Ratio::from_denom_and_numer_i32((i + j + 1) as i32, 1)),
+            Matrix::generate(5, 5, |i, j| if i == j { 3.into() } else { (((i * 3 + j) % 5) as i32 - 2).into() }),
+            Matrix::identity(1),
+        ];
+
+        for mat in samples.into_iter() {
+            let n = mat.cols;
+            let b = Matrix::generate(2, n, |i, j| Ratio::from_denom_and_numer_i32((j + 2) as i32, (i * i) as i32 - 3));
+            let x = mat.solve(&b).unwrap();
+
+            assert_eq!(mat.mul(&x).unwrap(), b);
+            assert_eq!(x, mat.inverse().unwrap().mul(&b).unwrap());
+        }
+
+        let singular = Matrix::from_vec_generic(vec![
+            vec![1, 2, 3],
+            vec![2, 4, 6],
+            vec![1, 0, 1],
+        ]).unwrap();
+
+        assert_eq!(singular.solve(&Matrix::zeros(1, 3)), Err(MatrixError::ZeroDeterminant));
+        assert_eq!(a.solve(&Matrix::zeros(1, 2)), Err(MatrixError::WrongDimension { expected: (1, 3), got: (1, 2) }));
+        assert_eq!(Matrix::zeros(3, 2).solve(&Matrix::zeros(1, 2)), Err(MatrixError::NotSquare(3, 2)));
+        assert_eq!(Matrix::empty().solve(&Matrix::empty()), Err(MatrixError::EmptyMatrix));
+    }
+}
@@ -1,8 +1,10 @@
 use crate::Ratio;
 
 mod from_points;
+mod pade;
 
 pub use from_points::{from_points, from_points_generic, cubic_2_points, quadratic_3_points, linear_2_points};
+pub use pade::pade_exp;
 
 /// [3, 4, 5] -> 3x^2 + 4x + 5
 #[derive(Clone, Debug, PartialEq)]
@@ -139,6 +141,53 @@ impl Polynomial {
         }
     }
 
+    /// It returns the antiderivative whose constant term is 0.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn integrate(&self) -> Self {
+        let mut result = Vec::with_capacity(self.coeffs.len() + 1);
+
+        for (ind, value) in self.coeffs.iter().rev().enumerate().rev() {
+            result.push(value.div_i32(ind as i32 + 1));
+        }
+
+        result.push(Ratio::zero());
+
+        Polynomial::from_vec(result)
+    }
+
+    /// f(k * x)
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn scale_variable(&self, k: &Ratio) -> Self {
+        let mut power = Ratio::one();
+        let mut result = Vec::with_capacity(self.coeffs.len());
+
+        for coeff in self.coeffs.iter().rev() {
+            result.push(coeff.mul(&power));
+            power.mul_mut(k);
+        }
+
+        result.reverse();
+
+        Polynomial::from_vec(result)
+    }
+
+    /// f(x + h)\
+    /// It applies synthetic division by `x - h` repeatedly (Taylor shift).
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn shift_variable(&self, h: &Ratio) -> Self {
+        let mut result = self.coeffs.clone();
+        let n = result.len() - 1;
+
+        for i in 0..n {
+            for j in 1..(n - i + 1) {
+                let d = result[j - 1].mul(h);
+                result[j].add_mut(&d);
+            }
+        }
+
+        Polynomial::from_vec(result)
+    }
+
     /// f(x)
     pub fn calc(&self, x: &Ratio) -> Ratio {
         let mut result = Ratio::zero();
@@ -227,9 +276,16 @@ impl Polynomial {
     }
 }
 
+/// ∫[a, b] p(x) dx, computed exactly with the antiderivative of `p`.
+pub fn definite_integral(p: &Polynomial, a: &Ratio, b: &Ratio) -> Ratio {
+    let antiderivative = p.integrate();
+
+    antiderivative.calc(b).sub(&antiderivative.calc(a))
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{Polynomial, Ratio};
+    use crate::{Polynomial, Ratio, definite_integral};
 
     #[test]
     fn newtons_method() {
@@ -268,6 +324,80 @@ mod tests {
         assert_eq!(p5.differentiate(), p5);
     }
 
+    #[test]
+    fn integrate_test() {
+        // 3x^2 + 4x + 5 -> x^3 + 2x^2 + 5x
+        assert_eq!(
+            Polynomial::from_vec_generic(vec![3, 4, 5]).integrate(),
+            Polynomial::from_vec_generic(vec![1, 2, 5, 0]),
+        );
+        assert_eq!(Polynomial::from_vec_generic(vec![0]).integrate(), Polynomial::from_vec_generic(vec![0]));
+
+        let p = Polynomial::from_vec(vec![
+            Ratio::from_i32(3),
+            Ratio::from_i32(-4),
+            Ratio::from_denom_and_numer_i32(2, 5),
+            Ratio::from_i32(6),
+        ]);
+        assert_eq!(p.integrate().differentiate(), p);
+
+        let x_sqr = Polynomial::from_vec_generic(vec![1, 0, 0]);
+        assert_eq!(definite_integral(&x_sqr, &Ratio::zero(), &Ratio::one()), Ratio::from_denom_and_numer_i32(3, 1));
+        assert_eq!(definite_integral(&x_sqr, &Ratio::one(), &Ratio::zero()), Ratio::from_denom_and_numer_i32(3, -1));
+
+        // ∫[-2, -1] (x^3 - x) dx = [x^4/4 - x^2/2] = (1/4 - 1/2) - (4 - 2) = -9/4
+        let p = Polynomial::from_vec_generic(vec![1, 0, -1, 0]);
+        assert_eq!(definite_integral(&p, &Ratio::from_i32(-2), &Ratio::from_i32(-1)), Ratio::from_denom_and_numer_i32(4, -9));
+
+        // odd function
+        assert_eq!(definite_integral(&p, &Ratio::from_i32(-3), &Ratio::from_i32(3)), Ratio::zero());
+        assert_eq!(definite_integral(&p, &Ratio::from_i32(5), &Ratio::from_i32(5)), Ratio::zero());
+    }
+
+    #[test]
+    fn change_of_variable_test() {
+        // 3x^3 - 4x^2 + 5x/2 + 6
+        let p = Polynomial::from_vec(vec![
+            Ratio::from_i32(3),
+            Ratio::from_i32(-4),
+            Ratio::from_denom_and_numer_i32(2, 5),
+            Ratio::from_i32(6),
+        ]);
+        let points = [-3, -1, 0, 1, 2, 7].map(Ratio::from_i32);
+        let ks = [
+            Ratio::from_i32(2),
+            Ratio::from_i32(-1),
+            Ratio::from_denom_and_numer_i32(3, 2),
+            Ratio::zero(),
+        ];
+
+        for k in ks.iter() {
+            let scaled = p.scale_variable(k);
+            let shifted = p.shift_variable(k);
+
+            for x in points.iter() {
+                assert_eq!(scaled.calc(x), p.calc(&x.mul(k)));
+                assert_eq!(shifted.calc(x), p.calc(&x.add(k)));
+            }
+        }
+
+        // (x + 1)^4 = x^4 + 4x^3 + 6x^2 + 4x + 1
+        assert_eq!(
+            Polynomial::from_vec_generic(vec![1, 0, 0, 0, 0]).shift_variable(&Ratio::one()),
+            Polynomial::from_vec_generic(vec![1, 4, 6, 4, 1]),
+        );
+
+        // (2x)^2 - 1
+        assert_eq!(
+            Polynomial::from_vec_generic(vec![1, 0, -1]).scale_variable(&Ratio::from_i32(2)),
+            Polynomial::from_vec_generic(vec![4, 0, -1]),
+        );
+
+        assert_eq!(p.scale_variable(&Ratio::zero()), Polynomial::from_vec_generic(vec![6]));
+        assert_eq!(p.shift_variable(&Ratio::from_i32(5)).shift_variable(&Ratio::from_i32(-5)), p);
+        assert_eq!(Polynomial::from_vec(vec![]).shift_variable(&Ratio::one()), Polynomial::from_vec(vec![]));
+    }
+
     #[test]
     fn to_string_test() {
         let v = vec![3.5, 4.25, 5.0, 6.5, 0.0, 1.0, 2.0, -3.0, -4.0];
@@ -7,19 +7,9 @@ use crate::{
 
 /// `p`: `Vec<(x, y)>` where `f(x) = y`
 pub fn from_points(p: Vec<(Ratio, Ratio)>) -> Result<Polynomial, MatrixError> {
-    let mut mat1 = Matrix::zeros(p.len(), p.len());
-    let mut mat2 = Matrix::zeros(1, p.len());
-
-    for i in 0..p.len() {
-        let mut curr = Ratio::one();
-
-        for j in 1..(p.len() + 1) {
-            *mat1.get_mut(i, p.len() - j) = curr.clone();
-            curr.mul_mut(&p[i].0);
-        }
-
-        *mat2.get_mut(i, 0) = p[i].1.clone();
-    }
+    let xs = p.iter().map(|(x, _)| x.clone()).collect::<Vec<Ratio>>();
+    let mat1 = Matrix::interpolation_system(&xs, p.len().max(1) - 1);
+    let mat2 = Matrix::generate(1, p.len(), |i, _| p[i].1.clone());
 
     let mat1_inv = match mat1.inverse() {
         Ok(m) => m,
@@ -139,11 +129,7 @@ pub fn quadratic_3_points(a: &Ratio, b: &Ratio, c: &Ratio, v1: &Ratio, v2: &Rati
         vec![v3.clone()],
     ]).unwrap();
 
-    let mat2 = match Matrix::from_vec(vec![
-        vec![a.mul(a), a.clone(), Ratio::one()],
-        vec![b.mul(b), b.clone(), Ratio::one()],
-        vec![c.mul(c), c.clone(), Ratio::one()],
-    ]).unwrap().inverse() {
+    let mat2 = match Matrix::interpolation_system(&[a.clone(), b.clone(), c.clone()], 2).inverse() {
         Ok(m) => m,
         Err(_) => if a == b {
             return linear_2_points(a, c, v1, v3);
@@ -179,7 +165,7 @@ pub fn linear_2_points(a: &Ratio, b: &Ratio, v1: &Ratio, v2: &Ratio) -> Polynomi
 
 #[cfg(test)]
 mod tests {
-    use crate::{from_points_generic, cubic_2_points, quadratic_3_points, linear_2_points, Ratio};
+    use crate::{from_points_generic, cubic_2_points, quadratic_3_points, linear_2_points, Matrix, Polynomial, Ratio};
 
     #[test]
     fn sqrt_10_test() {
@@ -240,4 +226,33 @@ mod tests {
         assert!(sqrt_funcs[4].calc(&1000.into()).div_i32(10).to_approx_string(11).starts_with("3.16227"));
         assert!(sqrt_funcs[5].calc(&1000.into()).div_i32(10).to_approx_string(11).starts_with("3.1622776"));
     }
+
+    #[test]
+    fn interpolation_system_test() {
+        let xs = [2, -1, 5].map(Ratio::from_i32);
+        let ys = [Ratio::from_i32(3), Ratio::from_denom_and_numer_i32(2, 7), Ratio::from_i32(-4)];
+
+        let system = Matrix::interpolation_system(&xs, 2);
+        assert_eq!(system, Matrix::from_vec_generic(vec![
+            vec![4, 2, 1],
+            vec![1, -1, 1],
+            vec![25, 5, 1],
+        ]).unwrap());
+
+        let solution = system.inverse().unwrap().mul(&Matrix::generate(1, 3, |i, _| ys[i].clone())).unwrap();
+        let solution = Polynomial::from_vec((0..3).map(|i| solution.get(i, 0).clone()).collect());
+
+        assert_eq!(solution, quadratic_3_points(&xs[0], &xs[1], &xs[2], &ys[0], &ys[1], &ys[2]));
+
+        for (x, y) in xs.iter().zip(ys.iter()) {
+            assert_eq!(&solution.calc(x), y);
+        }
+
+        // non-square systems
+        assert_eq!(Matrix::interpolation_system(&xs, 0), Matrix::from_vec_generic(vec![vec![1], vec![1], vec![1]]).unwrap());
+        assert_eq!(
+            Matrix::interpolation_system(&xs[..1], 3),
+            Matrix::from_vec_generic(vec![vec![8, 4, 2, 1]]).unwrap(),
+        );
+    }
 }
@@ -0,0 +1,81 @@
+use crate::{Polynomial, Ratio};
+
+/// It returns `(P, Q)`, where `P(x) / Q(x)` is the `[order/order]` Pade approximant of `e^x`.\
+/// It converges much faster than the Taylor series of the same degree, and works on a wider range.
+pub fn pade_exp(order: usize) -> (Polynomial, Polynomial) {
+    // P(x) = sum{k = 0..n} ((2n - k)! * n!) / ((2n)! * k! * (n - k)!) * x^k
+    // Q(x) = P(-x)
+    let n = order as i32;
+    let mut coeff = Ratio::one();
+    let mut numer = Vec::with_capacity(order + 1);
+    let mut denom = Vec::with_capacity(order + 1);
+
+    for k in 0..(n + 1) {
+        if k > 0 {
+            coeff.mul_i32_mut(n - k + 1);
+            coeff.div_i32_mut((2 * n - k + 1) * k);
+        }
+
+        denom.push(if k % 2 == 1 { coeff.neg() } else { coeff.clone() });
+        numer.push(coeff.clone());
+    }
+
+    // `Polynomial` stores the coefficient of the highest degree first
+    numer.reverse();
+    denom.reverse();
+
+    (Polynomial::from_vec(numer), Polynomial::from_vec(denom))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Polynomial, Ratio, e_iter, exp_iter, pade_exp};
+
+    #[test]
+    fn pade_exp_test() {
+        assert_eq!(pade_exp(0), (Polynomial::from_vec_generic(vec![1]), Polynomial::from_vec_generic(vec![1])));
+
+        // (1 + x/2) / (1 - x/2)
+        assert_eq!(
+            pade_exp(1),
+            (
+                Polynomial::from_vec(vec![Ratio::from_denom_and_numer_i32(2, 1), Ratio::one()]),
+                Polynomial::from_vec(vec![Ratio::from_denom_and_numer_i32(2, -1), Ratio::one()]),
+            ),
+        );
+
+        // (1 + x/2 + x^2/12) / (1 - x/2 + x^2/12)
+        assert_eq!(
+            pade_exp(2).0,
+            Polynomial::from_vec(vec![
+                Ratio::from_denom_and_numer_i32(12, 1),
+                Ratio::from_denom_and_numer_i32(2, 1),
+                Ratio::one(),
+            ]),
+        );
+
+        let e = e_iter(40);
+        let (p, q) = pade_exp(4);
+        let pade = p.calc(&Ratio::one()).div(&q.calc(&Ratio::one()));
+
+        // 1 + 1 + 1/2 + 1/6 + 1/24
+        let taylor = Ratio::from_denom_and_numer_i32(24, 65);
+
+        assert!(pade.sub(&e).abs().lt(&taylor.sub(&e).abs()));
+
+        // it gets more accurate as `order` gets bigger
+        for x in [-3, -1, 2, 3] {
+            let x = Ratio::from_i32(x);
+            let exact = exp_iter(&x, 80);
+
+            for order in 2..8 {
+                let (p, q) = pade_exp(order);
+                let pade = p.calc(&x).div(&q.calc(&x));
+                let (p_next, q_next) = pade_exp(order + 1);
+                let pade_next = p_next.calc(&x).div(&q_next.calc(&x));
+
+                assert!(pade_next.sub(&exact).abs().lt(&pade.sub(&exact).abs()));
+            }
+        }
+    }
+}
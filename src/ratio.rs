@@ -1,4 +1,5 @@
-use crate::{BigInt, gcd_bi};
+use crate::BigInt;
+use crate::bigint::funcs::gcd_binary_bi;
 
 mod arith;
 mod comp;
@@ -77,7 +78,7 @@ impl Ratio {
 
     #[cfg(test)]
     pub fn is_valid(&self) -> bool {
-        self.denom.is_valid() && self.numer.is_valid() && !self.denom.is_neg() && (!self.numer.is_zero() || self.denom.is_one()) && gcd_bi(&self.denom, &self.numer).is_one()
+        self.denom.is_valid() && self.numer.is_valid() && !self.denom.is_neg() && (!self.numer.is_zero() || self.denom.is_one()) && crate::gcd_bi(&self.denom, &self.numer).is_one()
     }
 
     // TODO: better name
@@ -87,7 +88,7 @@ impl Ratio {
             self.numer.neg_mut();
         }
 
-        let r = gcd_bi(&self.denom, &self.numer);
+        let r = gcd_binary_bi(&self.denom, &self.numer);
 
         if !r.is_one() {
             self.denom.div_mut(&r);
@@ -1,5 +1,79 @@
+use crate::{Ratio, impl_ops};
+
 mod add;
 mod div;
 mod mul;
 mod pow;
 mod sub;
+
+// `a / b` panics when `b` is 0, like `Ratio::div` does.
+impl_ops!(
+    Ratio,
+    Add, add, AddAssign, add_assign, add, add_mut;
+    Sub, sub, SubAssign, sub_assign, sub, sub_mut;
+    Mul, mul, MulAssign, mul_assign, mul, mul_mut;
+    Div, div, DivAssign, div_assign, div, div_mut
+);
+
+impl std::ops::Neg for Ratio {
+    type Output = Ratio;
+
+    fn neg(mut self) -> Ratio {
+        self.neg_mut();
+        self
+    }
+}
+
+impl std::ops::Neg for &Ratio {
+    type Output = Ratio;
+
+    fn neg(self) -> Ratio {
+        Ratio::neg(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ratio;
+
+    #[test]
+    fn ops_test() {
+        let a = Ratio::from_denom_and_numer_i32(3, 2);
+        let b = Ratio::from_denom_and_numer_i32(7, -5);
+        let c = Ratio::from_string("1.25").unwrap();
+        let d = Ratio::from_i32(-4);
+
+        // (a + b) / c - d
+        let answer = a.add(&b).div(&c).sub(&d);
+
+        assert_eq!((&a + &b) / &c - &d, answer);
+        assert_eq!((a.clone() + b.clone()) / c.clone() - d.clone(), answer);
+        assert_eq!(Ratio::from_denom_and_numer_i32(105, 416), answer);
+
+        // -(a * b) + c
+        assert_eq!(-(&a * &b) + &c, a.mul(&b).neg().add(&c));
+        assert_eq!(-d.clone(), Ratio::from_i32(4));
+        assert_eq!(-Ratio::zero(), Ratio::zero());
+
+        let mut e = a.clone();
+        e += &b;
+        e /= c.clone();
+        e -= d;
+        assert_eq!(e, answer);
+
+        e *= Ratio::zero();
+        assert!(e.is_zero());
+    }
+
+    #[test]
+    #[should_panic]
+    fn ops_div_zero_test() {
+        let _ = Ratio::one() / Ratio::zero();
+    }
+
+    #[test]
+    #[should_panic]
+    fn div_zero_test() {
+        let _ = Ratio::one().div(&Ratio::zero());
+    }
+}
@@ -4,6 +4,10 @@ impl Ratio {
 
     #[must_use = "method returns a new number and does not mutate the original value"]
     pub fn div(&self, other: &Ratio) -> Self {
+        if other.is_zero() {
+            panic!("Attempt to divide by zero: {self:?} / {other:?}");
+        }
+
         let result = Ratio::from_denom_and_numer(
             self.denom.mul(&other.numer),
             self.numer.mul(&other.denom),
@@ -15,6 +19,10 @@ impl Ratio {
     }
 
     pub fn div_mut(&mut self, other: &Ratio) {
+        if other.is_zero() {
+            panic!("Attempt to divide by zero: {self:?} / {other:?}");
+        }
+
         self.denom.mul_mut(&other.numer);
         self.numer.mul_mut(&other.denom);
         self.fit();
@@ -22,6 +30,18 @@ impl Ratio {
         #[cfg(test)] assert!(self.is_valid());
     }
 
+    /// It returns `None` when `other` is 0, instead of panicking.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn checked_div(&self, other: &Ratio) -> Option<Self> {
+        if other.is_zero() {
+            None
+        }
+
+        else {
+            Some(self.div(other))
+        }
+    }
+
     #[must_use = "method returns a new number and does not mutate the original value"]
     pub fn div_bi(&self, other: &BigInt) -> Self {
         let mut result = self.clone();
@@ -39,6 +39,16 @@ impl Ratio {
         self.numer.mul(&other.denom).comp(&other.numer.mul(&self.denom))
     }
 
+    /// It returns the bigger one without cloning. If they're the same, it returns `self`.
+    pub fn max_ref<'a>(&'a self, other: &'a Ratio) -> &'a Ratio {
+        if self.comp(other) == Ordering::Less { other } else { self }
+    }
+
+    /// It returns the smaller one without cloning. If they're the same, it returns `self`.
+    pub fn min_ref<'a>(&'a self, other: &'a Ratio) -> &'a Ratio {
+        if self.comp(other) == Ordering::Greater { other } else { self }
+    }
+
     /// self < 1
     pub fn lt_one(&self) -> bool {
         self.is_neg() || self.numer.lt(&self.denom)
@@ -100,6 +110,7 @@ impl Ord for Ratio {
 mod tests {
     use crate::Ratio;
     use std::cmp::Ordering;
+    use std::collections::HashMap;
 
     #[test]
     fn rat_comp_test() {
@@ -156,4 +167,89 @@ mod tests {
 
         assert!(a.lt_one() && a.lt_i32(1) || a.gt_one() && a.gt_i32(1) || a.eq_i32(1));
     }
+
+    #[test]
+    fn max_min_ref_test() {
+        let a = Ratio::from_denom_and_numer_i32(3, 2);
+        let b = Ratio::from_denom_and_numer_i32(5, -4);
+        let a2 = Ratio::from_denom_and_numer_i32(6, 4);
+
+        assert!(std::ptr::eq(a.max_ref(&b), &a));
+        assert!(std::ptr::eq(b.max_ref(&a), &a));
+        assert!(std::ptr::eq(a.min_ref(&b), &b));
+        assert!(std::ptr::eq(b.min_ref(&a), &b));
+
+        // same values -> `self`
+        assert!(std::ptr::eq(a.max_ref(&a2), &a));
+        assert!(std::ptr::eq(a2.max_ref(&a), &a2));
+        assert!(std::ptr::eq(a.min_ref(&a2), &a));
+        assert!(std::ptr::eq(a2.min_ref(&a), &a2));
+
+        let values = [3, -1, 4, 1, -5, 9, 2, -6].map(Ratio::from_i32);
+        let max = values.iter().fold(&values[0], |acc, n| acc.max_ref(n));
+        let min = values.iter().fold(&values[0], |acc, n| acc.min_ref(n));
+
+        assert!(std::ptr::eq(max, &values[5]));
+        assert!(std::ptr::eq(min, &values[7]));
+    }
+
+    #[test]
+    fn rat_ord_test() {
+        let mut values = vec![
+            Ratio::from_denom_and_numer_i32(3, 2),
+            Ratio::from_i32(-4),
+            Ratio::from_string("0.5").unwrap(),
+            Ratio::zero(),
+            Ratio::from_denom_and_numer_i32(-7, 3),
+            Ratio::from_denom_and_numer_i32(4, 2),
+            Ratio::from_string("1e30").unwrap(),
+        ];
+        values.sort();
+
+        assert_eq!(
+            values,
+            vec![
+                Ratio::from_i32(-4),
+                Ratio::from_denom_and_numer_i32(7, -3),
+                Ratio::zero(),
+                Ratio::from_denom_and_numer_i32(2, 1),
+                Ratio::from_denom_and_numer_i32(2, 1),
+                Ratio::from_denom_and_numer_i32(3, 2),
+                Ratio::from_string("1e30").unwrap(),
+            ],
+        );
+
+        // equal values from different constructions are `Eq` and `Ordering::Equal`
+        let half = Ratio::from_string("0.5").unwrap();
+        let half2 = Ratio::from_denom_and_numer_i32(-4, -2);
+        assert_eq!(half, half2);
+        assert_eq!(half.cmp(&half2), Ordering::Equal);
+
+        assert!(Ratio::from_denom_and_numer_i32(3, 1) < Ratio::from_denom_and_numer_i32(2, 1));
+        assert!(Ratio::from_i32(-1) >= Ratio::from_i32(-1));
+        assert_eq!(values.iter().max(), Some(&Ratio::from_string("1e30").unwrap()));
+    }
+
+    #[test]
+    fn rat_hash_test() {
+        let mut map = HashMap::new();
+
+        map.insert(Ratio::from_string("0.5").unwrap(), "a");
+        map.insert(Ratio::from_denom_and_numer_i32(2, 1), "b");
+        map.insert(Ratio::from_denom_and_numer_i32(-6, -3), "c");
+        map.insert(Ratio::from_ieee754_f64(0.5).unwrap(), "d");
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.get(&Ratio::from_denom_and_numer_i32(4, 2)), Some(&"d"));
+
+        map.insert(Ratio::from_denom_and_numer_i32(-2, 1), "e");
+        map.insert(Ratio::from_string("-0.5").unwrap(), "f");
+        assert_eq!(map.len(), 2);
+
+        map.insert(Ratio::zero(), "g");
+        map.insert(Ratio::from_denom_and_numer_i32(7, 0), "h");
+        map.insert(Ratio::from_i32(-3).add_i32(3), "i");
+        map.insert(Ratio::from_string("0.000").unwrap(), "j");
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.get(&Ratio::zero()), Some(&"j"));
+    }
 }
@@ -8,6 +8,9 @@ mod from;
 mod ieee754;
 mod into;
 
+#[cfg(feature = "serde")]
+mod serde;
+
 pub use ieee754::{inspect_ieee754_f32, inspect_ieee754_f64};
 
 impl Ratio {
@@ -233,6 +236,113 @@ impl Ratio {
         }
     }
 
+    /// Ratio { 10, -31 } -> "-1f/a" when `radix` is 16. Both parts are rendered by `UBigInt::to_string_radix`.\
+    /// It panics if `radix` is not in range 2..=36.
+    pub fn to_ratio_string_radix(&self, radix: u32) -> String {
+        let sign = if self.is_neg() { "-" } else { "" };
+        let numer = self.numer.abs().to_ubi().unwrap().to_string_radix(radix);
+
+        if self.denom.is_one() {
+            format!("{sign}{numer}")
+        } else {
+            format!("{sign}{numer}/{}", self.denom.to_ubi().unwrap().to_string_radix(radix))
+        }
+    }
+
+    /// It returns a decimal literal (`"-1.75"`) if `self` can be written exactly with at most `max_decimals` digits after the decimal point.\
+    /// Otherwise, it returns a JSON object `{"numer":"-4","denom":"3"}`, whose fields are decimal strings. If the result starts with `{`, it's not a number.
+    pub fn to_json_number(&self, max_decimals: usize) -> String {
+        let denom = self.denom.to_ubi().unwrap();
+        let mut rest = denom.clone();
+        let mut twos = 0;
+        let mut fives = 0;
+
+        while rest.rem_u32(2).is_zero() {
+            rest.div_u32_mut(2);
+            twos += 1;
+        }
+
+        while rest.rem_u32(5).is_zero() {
+            rest.div_u32_mut(5);
+            fives += 1;
+        }
+
+        // it's a terminating decimal iff the denominator is 2^a * 5^b, and it has max(a, b) digits after the decimal point
+        let decimals = twos.max(fives);
+
+        if !rest.is_one() || decimals > max_decimals {
+            return format!(
+                "{{\"numer\":\"{}\",\"denom\":\"{}\"}}",
+                self.numer.to_string_dec(),
+                self.denom.to_string_dec(),
+            );
+        }
+
+        let sign = if self.is_neg() { "-" } else { "" };
+        let digits = self.numer.abs().to_ubi().unwrap().mul(&UBigInt::from_u32(10).pow_u32(decimals as u32)).div(&denom).to_string_dec();
+
+        if decimals == 0 {
+            return format!("{sign}{digits}");
+        }
+
+        let digits = format!("{}{digits}", "0".repeat((decimals + 1).saturating_sub(digits.len())));
+        let (int_part, frac_part) = digits.split_at(digits.len() - decimals);
+
+        format!("{sign}{int_part}.{frac_part}")
+    }
+
+    /// It yields the digits after the decimal point of `abs(self)`, one by one, forever. 1/7 -> 1, 4, 2, 8, 5, 7, 1, 4, ...\
+    /// If the decimal representation terminates, it yields 0s after that. 1/4 -> 2, 5, 0, 0, ...
+    pub fn decimal_digits(&self) -> impl Iterator<Item = u8> {
+        let denom = self.denom.to_ubi().unwrap();
+        let mut remainder = self.numer.abs().to_ubi().unwrap().rem(&denom);
+
+        // long division
+        std::iter::from_fn(move || {
+            remainder.mul_u32_mut(10);
+            let (digit, rem) = remainder.div_rem(&denom);
+            remainder = rem;
+
+            // Safety: remainder < denom, so digit < 10
+            Some(digit.to_u32().unwrap() as u8)
+        })
+    }
+
+    /// It returns `round(self * 2^frac_bits)`, which is the mantissa of `self` in Q format with `frac_bits` fractional bits.\
+    /// Halfway cases are rounded away from 0, like `round_bi`. 1/3 -> 21845 when `frac_bits` is 16
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn to_fixed_point(&self, frac_bits: u32) -> BigInt {
+        self.mul_bi(&BigInt::exp2(frac_bits as u64)).round_bi()
+    }
+
+    /// Inverse of `to_fixed_point`: `m / 2^frac_bits`
+    pub fn from_fixed_point(m: &BigInt, frac_bits: u32) -> Self {
+        Ratio::from_bi(m.clone()).div_bi(&BigInt::exp2(frac_bits as u64))
+    }
+
+    /// Inverse of `to_ratio_string_radix`. "-1f/a" -> Ratio { 10, -31 } when `radix` is 16.\
+    /// The result doesn't have to be reduced: "2/4" is 1/2. It panics if `radix` is not in range 2..=36.
+    pub fn from_ratio_string_radix(s: &str, radix: u32) -> Result<Self, ConversionError> {
+        let (is_neg, s) = match s.strip_prefix('-') {
+            Some(s) => (true, s),
+            None => (false, s),
+        };
+
+        let (numer, denom) = match s.split_once('/') {
+            Some((numer, denom)) => (
+                UBigInt::from_string_radix(numer, radix)?,
+                UBigInt::from_string_radix(denom, radix)?,
+            ),
+            None => (UBigInt::from_string_radix(s, radix)?, UBigInt::one()),
+        };
+
+        if denom.is_zero() {
+            return Err(ConversionError::NotInRange { permitted: "non-zero denominator".to_string(), error: "0".to_string() });
+        }
+
+        Ok(Ratio::from_denom_and_numer(BigInt::from_ubi(denom, false), BigInt::from_ubi(numer, is_neg)))
+    }
+
     /// Ratio { 4, 7 } -> "1.75".
     /// The length of the returned string is less or equal to `digits`.
     /// If `digits` is less than 6, it'll count that as 6.
@@ -543,6 +653,133 @@ impl FromStr for Ratio {
 #[cfg(test)]
 mod tests {
     use crate::{Ratio, BigInt};
+    use crate::utils::rand_u32;
+
+    #[test]
+    fn fixed_point_test() {
+        let third = Ratio::from_denom_and_numer_i32(3, 1);
+
+        assert_eq!(third.to_fixed_point(16), BigInt::from_i32(21845));
+        assert_eq!(third.neg().to_fixed_point(16), BigInt::from_i32(-21845));
+        assert_eq!(third.mul_i32(2).to_fixed_point(16), BigInt::from_i32(43691));
+        assert_eq!(third.to_fixed_point(0), BigInt::zero());
+
+        // halfway cases
+        assert_eq!(Ratio::from_denom_and_numer_i32(1 << 17, 1).to_fixed_point(16), BigInt::one());
+        assert_eq!(Ratio::from_denom_and_numer_i32(1 << 17, -3).to_fixed_point(16), BigInt::from_i32(-2));
+
+        assert_eq!(Ratio::from_fixed_point(&BigInt::from_i32(21845), 16), Ratio::from_denom_and_numer_i32(65536, 21845));
+        assert_eq!(Ratio::from_fixed_point(&BigInt::from_i32(-3), 0), Ratio::from_i32(-3));
+
+        // dyadic ratios are exact
+        let mut seed = 0x9e37_79b9u32;
+
+        for frac_bits in [0, 1, 8, 16, 31, 64, 100] {
+            for _ in 0..20 {
+                let r = rand_u32(&mut seed);
+                let numer = BigInt::from_i64(r as i64 - (1 << 31)).mul(&BigInt::exp2(r as u64 % 40));
+                let exp = (r >> 8) % (frac_bits + 1);
+                let n = Ratio::from_denom_and_numer(BigInt::exp2(exp as u64), numer);
+                let m = n.to_fixed_point(frac_bits);
+
+                assert_eq!(Ratio::from_fixed_point(&m, frac_bits), n);
+                assert_eq!(Ratio::from_fixed_point(&m, frac_bits).to_fixed_point(frac_bits), m);
+            }
+        }
+
+        // the error is at most 2^-(frac_bits + 1)
+        let x = Ratio::from_string("3.14159265358979").unwrap();
+
+        for frac_bits in [4, 20, 40] {
+            let error = Ratio::from_fixed_point(&x.to_fixed_point(frac_bits), frac_bits).sub(&x).abs();
+            assert!(error.leq(&Ratio::from_denom_and_numer(BigInt::exp2(frac_bits as u64 + 1), BigInt::one())));
+        }
+    }
+
+    #[test]
+    fn decimal_digits_test() {
+        let seventh = Ratio::from_denom_and_numer_i32(7, 1);
+        assert_eq!(seventh.decimal_digits().take(18).collect::<Vec<_>>(), [1, 4, 2, 8, 5, 7].repeat(3));
+        assert_eq!(seventh.neg().decimal_digits().take(6).collect::<Vec<_>>(), vec![1, 4, 2, 8, 5, 7]);
+        assert_eq!(seventh.add_i32(3).decimal_digits().take(6).collect::<Vec<_>>(), vec![1, 4, 2, 8, 5, 7]);
+
+        let quarter = Ratio::from_denom_and_numer_i32(4, 1);
+        assert_eq!(quarter.decimal_digits().take(6).collect::<Vec<_>>(), vec![2, 5, 0, 0, 0, 0]);
+        assert_eq!(Ratio::from_i32(3).decimal_digits().take(3).collect::<Vec<_>>(), vec![0, 0, 0]);
+
+        // it's lazy: it doesn't have to calculate all the digits
+        assert_eq!(seventh.decimal_digits().nth(600_000), Some([1, 4, 2, 8, 5, 7][600_000 % 6]));
+
+        let n = Ratio::from_string("12.3456789012345678901234567890").unwrap();
+        let digits = n.decimal_digits().take(30).map(|d| (d + b'0') as char).collect::<String>();
+        assert_eq!(digits, "345678901234567890123456789000");
+    }
+
+    #[test]
+    fn json_number_test() {
+        let samples = vec![
+            ("1.75", 2, "1.75"),
+            ("1.75", 5, "1.75"),
+            ("-1.75", 2, "-1.75"),
+            ("1.75", 1, "{\"numer\":\"7\",\"denom\":\"4\"}"),
+            ("0.001", 3, "0.001"),
+            ("-0.0625", 4, "-0.0625"),
+            ("123", 0, "123"),
+            ("-123", 4, "-123"),
+            ("0", 0, "0"),
+            ("1e-20", 20, "0.00000000000000000001"),
+            ("12345678901234567890.5", 1, "12345678901234567890.5"),
+            ("-4/3", 100, "{\"numer\":\"-4\",\"denom\":\"3\"}"),
+            ("1/7", 6, "{\"numer\":\"1\",\"denom\":\"7\"}"),
+        ];
+
+        for (n, max_decimals, answer) in samples.into_iter() {
+            let n = if n.contains('/') {
+                Ratio::from_ratio_string_radix(n, 10).unwrap()
+            } else {
+                Ratio::from_string(n).unwrap()
+            };
+
+            let json = n.to_json_number(max_decimals);
+            assert_eq!(json, answer);
+
+            // a bare number round-trips without any precision loss
+            if !json.starts_with('{') {
+                assert_eq!(Ratio::from_string(&json).unwrap(), n);
+            }
+        }
+    }
+
+    #[test]
+    fn radix_string_test() {
+        let samples = [
+            Ratio::zero(),
+            Ratio::one(),
+            Ratio::from_denom_and_numer_i32(10, -31),
+            Ratio::from_denom_and_numer_i32(3, 2),
+            Ratio::from_i32(-255),
+            Ratio::from_string("3.14159265358979323846264338327950288").unwrap(),
+            Ratio::from_string("-1e-40").unwrap(),
+        ];
+
+        for n in samples.iter() {
+            for radix in [2, 8, 10, 16, 36] {
+                assert_eq!(&Ratio::from_ratio_string_radix(&n.to_ratio_string_radix(radix), radix).unwrap(), n);
+            }
+
+            assert_eq!(n.to_ratio_string_radix(10), n.to_ratio_string());
+        }
+
+        assert_eq!(Ratio::from_denom_and_numer_i32(10, -31).to_ratio_string_radix(16), "-1f/a");
+        assert_eq!(Ratio::from_i32(-255).to_ratio_string_radix(16), "-ff");
+        assert_eq!(Ratio::from_ratio_string_radix("-1F/A", 16).unwrap(), Ratio::from_denom_and_numer_i32(10, -31));
+        assert_eq!(Ratio::from_ratio_string_radix("2/4", 16).unwrap(), Ratio::from_denom_and_numer_i32(2, 1));
+        assert_eq!(Ratio::from_ratio_string_radix("-0", 16).unwrap(), Ratio::zero());
+        assert!(Ratio::from_ratio_string_radix("1/0", 16).is_err());
+        assert!(Ratio::from_ratio_string_radix("1/", 16).is_err());
+        assert!(Ratio::from_ratio_string_radix("1/2/3", 16).is_err());
+        assert!(Ratio::from_ratio_string_radix("g", 16).is_err());
+    }
 
     #[test]
     fn string_test() {
@@ -1,4 +1,4 @@
-use crate::{Ratio, BigInt, ConversionError};
+use crate::{Ratio, BigInt, UBigInt, ConversionError};
 
 impl Ratio {
 
@@ -73,14 +73,20 @@ impl Ratio {
             exp -= 1;
         }
 
-        let (mut frac, frac_rem) = self_clone.sub_i32(1).mul_i32(1 << 23).truncate_and_frac();
+        let (frac, frac_rem) = self_clone.sub_i32(1).mul_i32(1 << 23).truncate_and_frac();
+        let mut frac = frac.to_i32().unwrap() as u32;
+        let half = Ratio::from_denom_and_numer_i32(2, 1);
 
-        // IEEE754 chooses the closest approximation
-        if frac_rem.gt(&Ratio::from_denom_and_numer_i32(2, 1)) {
-            frac.add_i32_mut(1);
+        // IEEE754 chooses the closest approximation, and breaks ties to even
+        if frac_rem.gt(&half) || (frac_rem.eq(&half) && frac % 2 == 1) {
+            frac += 1;
         }
 
-        let mut frac = frac.to_i32().unwrap() as u32;
+        // 1.11...1 may be rounded up to 10.00...0
+        if frac == 1 << 23 {
+            frac = 0;
+            exp += 1;
+        }
 
         if exp < -126 {
             frac += 1 << 23;
@@ -108,7 +114,7 @@ impl Ratio {
 
         exp += 127;
 
-        if exp < 0 || exp > 255 {
+        if !(0..=254).contains(&exp) {
             return Err(ConversionError::NotInRange { permitted: "1.18e-38~3.4e38".to_string(), error: self.to_scientific_notation(5) });
         }
 
@@ -118,6 +124,13 @@ impl Ratio {
         unsafe { Ok(*rp) }
     }
 
+    /// Like `to_ieee754_f32`, but it never fails.\
+    /// Numbers too big for f32 become `f32::INFINITY` or `f32::NEG_INFINITY`, and numbers too small become `0.0` or `-0.0`.\
+    /// It's the same as `to_f32`.
+    pub fn to_f32_saturating(&self) -> f32 {
+        self.to_f32()
+    }
+
     /// If you don't know what `ieee754` is, you're okay to use this function.
     /// Though the ieee 754 standard distinguishes negative 0 and positive 0, it doesn't distinguish between them.
     /// It returns an error if `n` is NaN or Inf.
@@ -189,14 +202,20 @@ impl Ratio {
             exp -= 1;
         }
 
-        let (mut frac, frac_rem) = self_clone.sub_i32(1).mul_bi(&BigInt::from_i64(1 << 52)).truncate_and_frac();
+        let (frac, frac_rem) = self_clone.sub_i32(1).mul_bi(&BigInt::from_i64(1 << 52)).truncate_and_frac();
+        let mut frac = frac.to_i64().unwrap() as u64;
+        let half = Ratio::from_denom_and_numer_i32(2, 1);
 
-        // IEEE754 chooses the closest approximation
-        if frac_rem.gt(&Ratio::from_denom_and_numer_i32(2, 1)) {
-            frac.add_i32_mut(1);
+        // IEEE754 chooses the closest approximation, and breaks ties to even
+        if frac_rem.gt(&half) || (frac_rem.eq(&half) && frac % 2 == 1) {
+            frac += 1;
         }
 
-        let mut frac = frac.to_i64().unwrap() as u64;
+        // 1.11...1 may be rounded up to 10.00...0
+        if frac == 1 << 52 {
+            frac = 0;
+            exp += 1;
+        }
 
         if exp < -1022 {
             frac += 1 << 52;
@@ -224,7 +243,7 @@ impl Ratio {
 
         exp += 1023;
 
-        if exp < 0 || exp > 2047 {
+        if !(0..=2046).contains(&exp) {
             return Err(ConversionError::NotInRange { permitted: "2.23e-308~1.8e308".to_string(), error: self.to_scientific_notation(5) });
         }
 
@@ -233,6 +252,100 @@ impl Ratio {
 
         unsafe { Ok(*rp) }
     }
+
+    /// It returns the closest f64 to `self`. Unlike `to_ieee754_f64`, it never fails.\
+    /// Numbers too big for f64 become `f64::INFINITY` or `f64::NEG_INFINITY`.
+    pub fn to_f64(&self) -> f64 {
+        let bits = self.to_float_bits(53, 1023);
+
+        f64::from_bits(bits | ((self.is_neg() as u64) << 63))
+    }
+
+    /// It returns the closest f32 to `self`. Unlike `to_ieee754_f32`, it never fails.\
+    /// Numbers too big for f32 become `f32::INFINITY` or `f32::NEG_INFINITY`.
+    pub fn to_f32(&self) -> f32 {
+        let bits = self.to_float_bits(24, 127) as u32;
+
+        f32::from_bits(bits | ((self.is_neg() as u32) << 31))
+    }
+
+    // bits of `abs(self)` in an ieee754 format whose significand has `prec` bits (including the implicit 1) and whose max exponent is `emax`
+    // it rounds to the nearest, ties to even
+    fn to_float_bits(&self, prec: i64, emax: i64) -> u64 {
+        let emin = 1 - emax;
+        let inf_bits = ((2 * emax + 1) as u64) << (prec - 1);
+
+        if self.is_zero() {
+            return 0;
+        }
+
+        let numer = self.numer.abs().to_ubi().unwrap();
+        let denom = self.denom.to_ubi().unwrap();
+
+        // 2^(e - 1) < self < 2^(e + 1)
+        let e = numer.bits() as i64 - denom.bits() as i64;
+
+        // too big or too small: it doesn't have to divide the numbers
+        if e > emax + 1 {
+            return inf_bits;
+        }
+
+        if e < emin - prec - 1 {
+            return 0;
+        }
+
+        // self = (q + r / denom) / 2^k, where q has `prec + 12` or `prec + 13` bits
+        let k = prec + 12 - e;
+
+        let (q, r) = if k > 0 {
+            numer.mul(&UBigInt::exp2(k as u64)).div_rem(&denom)
+        } else {
+            numer.div_rem(&denom.mul(&UBigInt::exp2(-k as u64)))
+        };
+
+        // Safety: q < 2^(prec + 13) <= 2^66
+        let q = q.to_u128().unwrap();
+        let sticky = !r.is_zero();
+        let q_bits = 128 - q.leading_zeros() as i64;
+
+        // self = 1.xxx * 2^exp
+        let exp = q_bits - 1 - k;
+
+        if exp > emax {
+            return inf_bits;
+        }
+
+        // number of bits to round off: subnormal numbers have less bits
+        let drop = (q_bits - prec).max(emin - prec + 1 + k);
+
+        if drop > q_bits {
+            return 0;
+        }
+
+        let mut mantissa = (q >> drop) as u64;
+        let rem = q & ((1 << drop) - 1);
+        let half = 1 << (drop - 1);
+
+        if rem > half || (rem == half && (sticky || mantissa & 1 == 1)) {
+            mantissa += 1;
+        }
+
+        // self = mantissa * 2^(drop - k)
+        // if `mantissa` is 10.00...0 after rounding, the addition carries to the exponent field
+        let biased_exp = drop - k + prec - 1 + emax;
+        let result = (((biased_exp - 1) as u64) << (prec - 1)) + mantissa;
+
+        result.min(inf_bits)
+    }
+
+    /// `self - Ratio::from_ieee754_f64(self.to_ieee754_f64())`, which is exactly how much precision `self` loses when converted to f64.\
+    /// It returns an error when `self.to_ieee754_f64()` does.
+    pub fn f64_round_trip_error(&self) -> Result<Ratio, ConversionError> {
+        let f = self.to_ieee754_f64()?;
+
+        // Safety: `f` is neither NaN nor Inf
+        Ok(self.sub(&Ratio::from_ieee754_f64(f).unwrap()))
+    }
 }
 
 /// You may find this function useful when you're dealing with [ieee 754 numbers](https://en.wikipedia.org/wiki/IEEE_754).\
@@ -343,6 +456,7 @@ pub fn inspect_ieee754_f64(n: f64) -> Result<(bool, i32, u64), ConversionError>
 mod tests {
     use crate::consts::RUN_ALL_TESTS;
     use super::*;
+    use crate::utils::{rand_u32, rand_u64};
 
     #[cfg(feature = "rand")]
     use crate::err::ConversionError;
@@ -650,4 +764,234 @@ mod tests {
             v.into_iter().map(|n| -n).collect(),
         ].concat()
     }
+
+    #[test]
+    fn to_f32_saturating_test() {
+        let max = Ratio::from_ieee754_f32(f32::MAX).unwrap();
+        let ulp = Ratio::from_ieee754_f32(2.0f32.powi(104)).unwrap();
+
+        assert_eq!(max.to_f32_saturating(), f32::MAX);
+        assert_eq!(max.add(&ulp.div_i32(4)).to_f32_saturating(), f32::MAX);
+        assert_eq!(max.add(&ulp.div_i32(2)).to_f32_saturating(), f32::INFINITY);
+        assert_eq!(max.neg().sub(&ulp).to_f32_saturating(), f32::NEG_INFINITY);
+        assert_eq!(Ratio::from_string("1e100").unwrap().to_f32_saturating(), f32::INFINITY);
+        assert_eq!(Ratio::from_string("-1e100").unwrap().to_f32_saturating(), f32::NEG_INFINITY);
+
+        let tiny = Ratio::from_string("1e-60").unwrap().to_f32_saturating();
+        assert_eq!(tiny, 0.0);
+        assert!(tiny.is_sign_positive());
+
+        let tiny = Ratio::from_string("-1e-60").unwrap().to_f32_saturating();
+        assert_eq!(tiny, 0.0);
+        assert!(tiny.is_sign_negative());
+
+        assert!(Ratio::zero().to_f32_saturating().is_sign_positive());
+
+        // 2 - 2^-25 -> 2
+        assert_eq!(Ratio::from_string("1.99999998").unwrap().to_f32_saturating(), 2.0);
+
+        // ties to even: 1 + 2^-24 -> 1, 1 + 3 * 2^-24 -> 1 + 2^-22
+        let eps = Ratio::from_ieee754_f32(2.0f32.powi(-24)).unwrap();
+        assert_eq!(Ratio::one().add(&eps).to_f32_saturating(), 1.0);
+        assert_eq!(Ratio::one().add(&eps.mul_i32(3)).to_f32_saturating(), 1.0 + 2.0f32.powi(-22));
+
+        // subnormal ties to even: 2.5 * 2^-149 -> 2 * 2^-149, 3.5 * 2^-149 -> 4 * 2^-149
+        let denom = BigInt::exp2(150);
+        assert_eq!(Ratio::from_denom_and_numer(denom.clone(), BigInt::from_i32(5)).to_f32_saturating().to_bits(), 2);
+        assert_eq!(Ratio::from_denom_and_numer(denom.clone(), BigInt::from_i32(7)).to_f32_saturating().to_bits(), 4);
+        assert_eq!(Ratio::from_denom_and_numer(denom, BigInt::from_i32(-5)).to_f32_saturating().to_bits(), 2 | (1 << 31));
+
+        let mut seed = 12345u32;
+
+        for _ in 0..256 {
+            let f = f32::from_bits(rand_u32(&mut seed));
+
+            if !f.is_finite() {
+                continue;
+            }
+
+            let r = Ratio::from_ieee754_f32(f).unwrap();
+            assert_eq!(r.to_f32_saturating(), f);
+
+            // the number right between `f` and the next float
+            if f.is_normal() && f.abs() < f32::MAX {
+                let next = Ratio::from_ieee754_f32(f32::from_bits(seed + 1)).unwrap();
+                let mid = r.add(&next).div_i32(2);
+                let expected = if seed & 1 == 0 { f } else { f32::from_bits(seed + 1) };
+
+                assert_eq!(mid.to_f32_saturating(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn ieee754_rounding_test() {
+        // ties to even: 1 + 2^-24 -> 1, 1 + 3 * 2^-24 -> 1 + 2^-22
+        let eps = Ratio::from_ieee754_f32(2.0f32.powi(-24)).unwrap();
+        assert_eq!(Ratio::one().add(&eps).to_ieee754_f32().unwrap(), 1.0);
+        assert_eq!(Ratio::one().add(&eps.mul_i32(3)).to_ieee754_f32().unwrap(), 1.0 + 2.0f32.powi(-22));
+
+        // 2 - 2^-25 -> 2: the carry of the mantissa increments the exponent
+        assert_eq!(Ratio::from_string("1.99999998").unwrap().to_ieee754_f32().unwrap(), 2.0);
+
+        let max = Ratio::from_ieee754_f32(f32::MAX).unwrap();
+        let ulp = Ratio::from_ieee754_f32(2.0f32.powi(104)).unwrap();
+
+        assert_eq!(max.add(&ulp.div_i32(4)).to_ieee754_f32().unwrap(), f32::MAX);
+        assert!(max.add(&ulp.div_i32(2)).to_ieee754_f32().is_err());
+        assert!(max.neg().sub(&ulp.div_i32(2)).to_ieee754_f32().is_err());
+
+        // the same for f64: 1 + 2^-53 -> 1, 1 + 3 * 2^-53 -> 1 + 2^-51
+        let eps = Ratio::from_ieee754_f64(2.0f64.powi(-53)).unwrap();
+        assert_eq!(Ratio::one().add(&eps).to_ieee754_f64().unwrap(), 1.0);
+        assert_eq!(Ratio::one().add(&eps.mul_i32(3)).to_ieee754_f64().unwrap(), 1.0 + 2.0f64.powi(-51));
+
+        // 2 - 2^-54 -> 2
+        assert_eq!(Ratio::from_i32(2).sub(&eps.div_i32(2)).to_ieee754_f64().unwrap(), 2.0);
+
+        let max = Ratio::from_ieee754_f64(f64::MAX).unwrap();
+        let ulp = Ratio::from_ieee754_f64(2.0f64.powi(971)).unwrap();
+
+        assert_eq!(max.add(&ulp.div_i32(4)).to_ieee754_f64().unwrap(), f64::MAX);
+        assert!(max.add(&ulp.div_i32(2)).to_ieee754_f64().is_err());
+        assert!(max.neg().sub(&ulp.div_i32(2)).to_ieee754_f64().is_err());
+    }
+
+    #[test]
+    fn to_f64_test() {
+        let mut seed = 12345u64;
+
+        for _ in 0..1024 {
+            let f = f64::from_bits(rand_u64(&mut seed));
+
+            if !f.is_finite() {
+                continue;
+            }
+
+            let r = Ratio::from_ieee754_f64(f).unwrap();
+            assert_eq!(r.to_f64(), f);
+            assert_eq!(r.to_f32(), f as f32);
+
+            // the number right between `f` and the next float: ties to even
+            if f.abs() < f64::MAX {
+                let next = Ratio::from_ieee754_f64(f64::from_bits(seed + 1)).unwrap();
+                let expected = if seed & 1 == 0 { f } else { f64::from_bits(seed + 1) };
+
+                assert_eq!(r.add(&next).div_i32(2).to_f64(), expected);
+            }
+        }
+
+        for f in [
+            f64::MAX, f64::MIN, f64::MIN_POSITIVE, -f64::MIN_POSITIVE,
+            f64::from_bits(1), f64::from_bits(0x000f_ffff_ffff_ffff),
+            1.0, -1.0, 0.1, 1.0 / 3.0, 1e300, 1e-300, 5e-324,
+        ] {
+            assert_eq!(Ratio::from_ieee754_f64(f).unwrap().to_f64(), f);
+        }
+
+        for f in [f32::MAX, f32::MIN_POSITIVE, f32::from_bits(1), 0.1, 3.0e-40] {
+            assert_eq!(Ratio::from_ieee754_f32(f).unwrap().to_f32(), f);
+        }
+
+        // the same as the compiler
+        for s in ["0.1", "3.14159265358979323846", "1e-320", "2.4703282292062328e-324", "123456789e-30", "-98765.4321"] {
+            let r = Ratio::from_string(s).unwrap();
+
+            assert_eq!(r.to_f64(), s.parse::<f64>().unwrap(), "{s}");
+            assert_eq!(r.to_f32(), s.parse::<f32>().unwrap(), "{s}");
+        }
+
+        assert_eq!(Ratio::from_denom_and_numer_i32(3, 1).to_f64(), 1.0 / 3.0);
+        assert_eq!(Ratio::zero().to_f64(), 0.0);
+        assert_eq!(Ratio::from_string("1e400").unwrap().to_f64(), f64::INFINITY);
+        assert_eq!(Ratio::from_string("-1e400").unwrap().to_f64(), f64::NEG_INFINITY);
+        assert_eq!(Ratio::from_string("1e40").unwrap().to_f32(), f32::INFINITY);
+        assert_eq!(Ratio::from_string("1e-400").unwrap().to_f64(), 0.0);
+        assert_eq!(Ratio::from_bi(BigInt::exp2(1024)).to_f64(), f64::INFINITY);
+
+        // huge numerator and denominator
+        let huge = Ratio::from_denom_and_numer(BigInt::exp2(5000).add_i32(1), BigInt::exp2(5001).sub_i32(1));
+        assert_eq!(huge.to_f64(), 2.0);
+    }
+
+    #[test]
+    fn to_f32_agreement_test() {
+        // (sample, expected bits)
+        let mut samples = vec![];
+
+        for s in [
+            // normal
+            "1", "-0.1", "3.14159265358979323846", "1e-37", "-3.4e38",
+
+            // subnormal
+            "1e-40", "-1e-44", "1.4e-45", "7e-46", "1e-46",
+
+            // overflow
+            "3.5e38", "-1e39", "1e100",
+        ] {
+            samples.push((Ratio::from_string(s).unwrap(), s.parse::<f32>().unwrap().to_bits()));
+        }
+
+        // subnormals, the boundary between subnormals and normals, and random bits
+        let mut bits = vec![1, 2, 3, 0x7f_fffe, 0x7f_ffff, 0x80_0000, 0x8000_0001, 0x807f_ffff];
+        let mut seed = 54321u32;
+
+        for _ in 0..256 {
+            bits.push(rand_u32(&mut seed));
+        }
+
+        for b in bits.into_iter() {
+            let f = f32::from_bits(b);
+
+            if !f.is_finite() || f.abs() == f32::MAX || f == 0.0 {
+                continue;
+            }
+
+            let r = Ratio::from_ieee754_f32(f).unwrap();
+            let next = Ratio::from_ieee754_f32(f32::from_bits(b + 1)).unwrap();
+
+            // the number right between `f` and the next float: ties to even
+            samples.push((r.add(&next).div_i32(2), if b & 1 == 0 { b } else { b + 1 }));
+
+            // a number slightly above the midpoint: 5/8 of the way to the next float
+            samples.push((r.mul_i32(3).add(&next).div_i32(4).add(&next).div_i32(2), b + 1));
+
+            samples.push((r, b));
+        }
+
+        for (n, expected) in samples.iter() {
+            assert_eq!(n.to_f32().to_bits(), *expected, "{}", n.to_scientific_notation(8));
+            assert_eq!(n.to_f32_saturating().to_bits(), *expected, "{}", n.to_scientific_notation(8));
+        }
+    }
+
+    #[test]
+    fn f64_round_trip_error_test() {
+        for n in ["0", "3/8", "-3/8", "1", "12345", "-1/1024", "1180591620717411303424"] {
+            let n = Ratio::from_ratio_string_radix(n, 10).unwrap();
+            assert_eq!(n.f64_round_trip_error().unwrap(), Ratio::zero());
+        }
+
+        // 1/3 = 6004799503160661 / 2^54 + 1 / (3 * 2^54)
+        let third = Ratio::from_denom_and_numer_i32(3, 1);
+        assert_eq!(
+            third.f64_round_trip_error().unwrap(),
+            Ratio::from_denom_and_numer(BigInt::pow2(54).mul_i32(3), BigInt::one()),
+        );
+        assert_eq!(
+            third.neg().f64_round_trip_error().unwrap(),
+            Ratio::from_denom_and_numer(BigInt::pow2(54).mul_i32(3), BigInt::from_i32(-1)),
+        );
+
+        // the error is at most a half of ulp
+        for n in ["3.14159265358979323846", "-0.1", "1e100", "1e-100"] {
+            let n = Ratio::from_string(n).unwrap();
+            let error = n.f64_round_trip_error().unwrap();
+
+            assert!(!error.is_zero());
+            assert!(error.abs().leq(&n.abs().div(&Ratio::from_bi(BigInt::pow2(53)))));
+        }
+
+        assert!(Ratio::from_string("1e400").unwrap().f64_round_trip_error().is_err());
+    }
 }
@@ -0,0 +1,69 @@
+use crate::{Ratio, BigInt};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+/// It's serialized to `"numer/denom"`, like `"-3/7"`. The string is always in the reduced form, so equal ratios are serialized to the same string.
+impl Serialize for Ratio {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("{}/{}", self.numer.to_string_dec(), self.denom.to_string_dec()))
+    }
+}
+
+/// It accepts `"numer/denom"` and the strings that `Ratio::from_string` accepts, like `"-0.375"` or `"1.5e30"`.
+impl<'de> Deserialize<'de> for Ratio {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+
+        let result = match s.split_once('/') {
+            Some((numer, denom)) => match (BigInt::from_string(numer.trim()), BigInt::from_string(denom.trim())) {
+                (Ok(_), Ok(denom)) if denom.is_zero() => {
+                    return Err(de::Error::custom(format!("invalid Ratio {s:?}: the denominator is 0")));
+                },
+                (Ok(numer), Ok(denom)) => Ok(Ratio::from_denom_and_numer(denom, numer)),
+                (Err(e), _) | (_, Err(e)) => Err(e),
+            },
+            None => Ratio::from_string(&s),
+        };
+
+        result.map_err(|e| de::Error::custom(format!("invalid Ratio {s:?}: {e:?}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ratio;
+
+    #[test]
+    fn serde_test() {
+        let numbers = [
+            Ratio::zero(),
+            Ratio::from_i32(-7),
+            Ratio::from_denom_and_numer_i32(7, -3),
+            Ratio::from_string("1.25e40").unwrap(),
+            Ratio::from_denom_and_numer_i32(3, 1).pow_i32(100),
+        ];
+
+        for n in numbers.iter() {
+            let json = serde_json::to_string(n).unwrap();
+
+            assert_eq!(&serde_json::from_str::<Ratio>(&json).unwrap(), n);
+        }
+
+        assert_eq!(serde_json::to_string(&Ratio::from_denom_and_numer_i32(-14, 6)).unwrap(), "\"-3/7\"");
+        assert_eq!(serde_json::to_string(&Ratio::from_i32(5)).unwrap(), "\"5/1\"");
+
+        for (s, answer) in [
+            ("\"-3/7\"", Ratio::from_denom_and_numer_i32(7, -3)),
+            ("\"6/-14\"", Ratio::from_denom_and_numer_i32(7, -3)),
+            ("\"-0.375\"", Ratio::from_denom_and_numer_i32(8, -3)),
+            ("\"1.5e3\"", Ratio::from_i32(1500)),
+            ("\"42\"", Ratio::from_i32(42)),
+            ("\"0/5\"", Ratio::zero()),
+        ] {
+            assert_eq!(serde_json::from_str::<Ratio>(s).unwrap(), answer);
+        }
+
+        for s in ["\"1/0\"", "\"1/2/3\"", "\"a/3\"", "\"\"", "0.5"] {
+            assert!(serde_json::from_str::<Ratio>(s).is_err());
+        }
+    }
+}
@@ -1,20 +1,24 @@
-use crate::{Ratio, BigInt, gcd_bi};
+use crate::{Ratio, BigInt, WeightedMeanError, gcd_bi, lcm_bi};
 
 mod atrigo;
+mod cont_frac;
 mod exp;
 mod hyper;
 mod ln;
 mod pow;
 mod root;
+mod taylor;
 mod trigo;
 
 pub use atrigo::{asin_iter, acos_iter, atan_iter};
-pub use exp::exp_iter;
+pub use cont_frac::{sqrt_continued_fraction, sqrt_cf_approx, solve_pell, rational_reconstruct};
+pub use exp::{exp_iter, exp_iter_bounded, exp_m1_iter};
 pub use hyper::{sinh_iter, cosh_iter, tanh_iter};
-pub use ln::{ln_iter, log_iter};
+pub use ln::{ln_iter, ln_1p_iter, log_iter, log_base_iter, log10_iter};
 pub use pow::pow_iter;
-pub use root::{sqrt_iter, cbrt_iter};
-pub use trigo::{sin_iter, cos_iter, tan_iter};
+pub use root::{sqrt_iter, sqrt_prec, cbrt_iter};
+pub use taylor::TaylorSeries;
+pub use trigo::{sin_iter, cos_iter, tan_iter, tan_double, sin_from_tan_half, cos_from_tan_half};
 
 /// a = v1 / v3, b = v2 / v3 where the return value is `(v1, v2, v3)`
 #[must_use = "method returns a new number and does not mutate the original value"]
@@ -34,6 +38,182 @@ pub fn common_denom(a: &Ratio, b: &Ratio) -> (BigInt, BigInt, BigInt) {
     )
 }
 
+/// It returns `(d, [n1, n2, ...])` where `ratios[i] = n_i / d` and `d` is the lcm of the denominators.\
+/// It returns `(1, [])` if `ratios` is empty.
+#[must_use = "method returns a new number and does not mutate the original value"]
+pub fn common_denominator(ratios: &[Ratio]) -> (BigInt, Vec<BigInt>) {
+    let mut denom = BigInt::one();
+
+    for r in ratios.iter() {
+        denom = lcm_bi(&denom, &r.denom);
+    }
+
+    let numers = ratios.iter().map(
+        |r| r.numer.mul(&denom.div(&r.denom))
+    ).collect();
+
+    (denom, numers)
+}
+
+/// Σ(v * w) / Σw\
+/// It returns an error if the lengths of `values` and `weights` are different, or the sum of `weights` is 0.
+#[must_use = "method returns a new number and does not mutate the original value"]
+pub fn weighted_mean(values: &[Ratio], weights: &[Ratio]) -> Result<Ratio, WeightedMeanError> {
+    if values.len() != weights.len() {
+        return Err(WeightedMeanError::LengthMismatch { values: values.len(), weights: weights.len() });
+    }
+
+    let mut weighted_sum = Ratio::zero();
+    let mut weight_sum = Ratio::zero();
+
+    for (v, w) in values.iter().zip(weights.iter()) {
+        weighted_sum.add_mut(&v.mul(w));
+        weight_sum.add_mut(w);
+    }
+
+    if weight_sum.is_zero() {
+        Err(WeightedMeanError::ZeroTotalWeight)
+    }
+
+    else {
+        Ok(weighted_sum.div(&weight_sum))
+    }
+}
+
+/// 3t^2 - 2t^3 where t = clamp((x - edge0) / (edge1 - edge0), 0, 1)\
+/// It panics when `edge0` and `edge1` are the same.
+#[must_use = "method returns a new number and does not mutate the original value"]
+pub fn smoothstep(edge0: &Ratio, edge1: &Ratio, x: &Ratio) -> Ratio {
+    let mut t = x.sub(edge0).div(&edge1.sub(edge0));
+
+    if t.is_neg() {
+        t = Ratio::zero();
+    }
+
+    else if t.gt_one() {
+        t = Ratio::one();
+    }
+
+    // t^2 * (3 - 2t)
+    let result = t.mul(&t).mul(&Ratio::from_i32(3).sub(&t.mul_i32(2)));
+
+    #[cfg(test)] assert!(result.is_valid());
+
+    result
+}
+
+/// It finds a root of `f` in `[lo, hi]` by bisecting the interval `iter` times, and returns the midpoint of the last interval.\
+/// The result is within `(hi - lo) / 2^(iter + 1)` of a root if `f` is continuous.
+/// It panics when `f(lo)` and `f(hi)` have the same sign.
+pub fn bisect(f: impl Fn(&Ratio) -> Ratio, lo: &Ratio, hi: &Ratio, iter: usize) -> Ratio {
+    let f_lo = f(lo);
+    let f_hi = f(hi);
+
+    if f_lo.is_zero() {
+        return lo.clone();
+    }
+
+    if f_hi.is_zero() {
+        return hi.clone();
+    }
+
+    if f_lo.is_neg() == f_hi.is_neg() {
+        panic!("f(lo) and f(hi) must have opposite signs: f({lo}) = {f_lo}, f({hi}) = {f_hi}");
+    }
+
+    let lo_is_neg = f_lo.is_neg();
+    let mut lo = lo.clone();
+    let mut hi = hi.clone();
+
+    for _ in 0..iter {
+        let mid = lo.add(&hi).div_i32(2);
+        let f_mid = f(&mid);
+
+        if f_mid.is_zero() {
+            return mid;
+        }
+
+        if f_mid.is_neg() == lo_is_neg {
+            lo = mid;
+        }
+
+        else {
+            hi = mid;
+        }
+    }
+
+    lo.add(&hi).div_i32(2)
+}
+
+/// It yields `1, base, base^2, base^3, ...` forever. Each element is computed by multiplying the previous one by `base`.
+pub fn powers_of(base: &Ratio) -> impl Iterator<Item = Ratio> {
+    let base = base.clone();
+
+    std::iter::successors(Some(Ratio::one()), move |prev| Some(prev.mul(&base)))
+}
+
+/// `a + a * r + a * r^2 + a * r^3 + ...`, which is `a / (1 - r)`. The result is exact.\
+/// It returns `None` when `abs(r) >= 1`, which is when the series diverges.
+pub fn geometric_series_infinite(a: &Ratio, r: &Ratio) -> Option<Ratio> {
+    if !r.abs().lt_one() {
+        return None;
+    }
+
+    Some(a.div(&Ratio::one().sub(r)))
+}
+
+/// It sums `values` by adding adjacent pairs, then adding adjacent pairs of the sums, and so on.\
+/// The result is the same as adding them one by one, but it's faster because the intermediate denominators are much smaller.
+pub fn sum_balanced(values: &[Ratio]) -> Ratio {
+    sum_balanced_worker(values, |_| {})
+}
+
+// it calls `on_sum` with every intermediate sum: the tests use it to measure the denominators
+fn sum_balanced_worker(values: &[Ratio], mut on_sum: impl FnMut(&Ratio)) -> Ratio {
+    if values.is_empty() {
+        return Ratio::zero();
+    }
+
+    let mut curr = values.to_vec();
+
+    while curr.len() > 1 {
+        curr = curr.chunks(2).map(
+            |pair| if pair.len() == 2 {
+                let sum = pair[0].add(&pair[1]);
+                on_sum(&sum);
+
+                sum
+            } else {
+                pair[0].clone()
+            }
+        ).collect();
+    }
+
+    // Safety: `values` is not empty
+    curr.pop().unwrap()
+}
+
+/// It multiplies the first `n` elements of `terms`. If `terms` has less than `n` elements, it multiplies all of them.\
+/// The result is exact. It cancels common factors before each multiplication, so it's much faster than calling `mul` `n` times when the terms are small.
+pub fn continued_product(terms: impl Iterator<Item = Ratio>, n: usize) -> Ratio {
+    let mut result = Ratio::one();
+
+    for term in terms.take(n) {
+        // (a / b) * (c / d) = ((a / gcd(a, d)) * (c / gcd(b, c))) / ((b / gcd(b, c)) * (d / gcd(a, d)))
+        // if a/b and c/d are reduced, so is the result
+        let g1 = gcd_bi(&result.numer, &term.denom);
+        let g2 = gcd_bi(&term.numer, &result.denom);
+
+        let numer = result.numer.div(&g1).mul(&term.numer.div(&g2));
+        let denom = result.denom.div(&g2).mul(&term.denom.div(&g1));
+
+        // Safety: numer and denom are coprime, and the sign of `denom` is not changed
+        result = Ratio::from_denom_and_numer_raw(denom, numer);
+    }
+
+    result
+}
+
 impl Ratio {
 
     #[must_use = "method returns a new number and does not mutate the original value"]
@@ -84,6 +264,19 @@ impl Ratio {
         *self = self.reci();
     }
 
+    /// 1 / self\
+    /// It returns `None` when `self` is 0, instead of panicking.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn checked_reci(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        }
+
+        else {
+            Some(self.reci())
+        }
+    }
+
     #[must_use = "method returns a new number and does not mutate the original value"]
     pub fn truncate(&self) -> Self {
         Ratio::from_bi(self.truncate_bi())
@@ -163,6 +356,45 @@ impl Ratio {
         }
     }
 
+    /// It returns the largest power of 2 less than or equal to `self`. 3 -> 2, 1/3 -> 1/4\
+    /// It panics when `self` is not positive.
+    pub fn floor_pow2(&self) -> Ratio {
+        pow2_i64(self.floor_log2())
+    }
+
+    /// It returns the smallest power of 2 greater than or equal to `self`. 3 -> 4, 1/3 -> 1/2\
+    /// It panics when `self` is not positive.
+    pub fn ceil_pow2(&self) -> Ratio {
+        let exp = self.floor_log2();
+        let floor = pow2_i64(exp);
+
+        if floor.eq(self) {
+            floor
+        }
+
+        else {
+            pow2_i64(exp + 1)
+        }
+    }
+
+    // truncate(log2(self))
+    fn floor_log2(&self) -> i64 {
+        if self.is_neg() || self.is_zero() {
+            panic!("Attempt to get a power of 2 near a non-positive number: {self:?}");
+        }
+
+        // it's either the answer or the answer + 1
+        let exp = self.numer.log2().to_i64().unwrap() - self.denom.log2().to_i64().unwrap();
+
+        if pow2_i64(exp).gt(self) {
+            exp - 1
+        }
+
+        else {
+            exp
+        }
+    }
+
     /// If this method and [this method] behave differently, that's an error.
     ///
     /// [this method]: https://doc.rust-lang.org/stable/std/primitive.f64.html#method.round
@@ -190,6 +422,81 @@ impl Ratio {
         }
     }
 
+    /// It rounds half to even (banker's rounding). 0.5 -> 0, 1.5 -> 2, 2.5 -> 2, -0.5 -> 0
+    pub fn round_even(&self) -> Self {
+        Ratio::from_bi(self.round_even_bi())
+    }
+
+    /// It rounds half to even (banker's rounding). 0.5 -> 0, 1.5 -> 2, 2.5 -> 2, -0.5 -> 0
+    pub fn round_even_bi(&self) -> BigInt {
+        let (trun, frac) = self.truncate_and_frac();
+
+        let numer_double_abs = frac.numer.mul_i32(2).abs();
+        use std::cmp::Ordering;
+
+        let away_from_zero = match numer_double_abs.comp(&frac.denom) {
+            Ordering::Less => false,
+            Ordering::Greater => true,
+
+            // half way between the two -> the even one
+            Ordering::Equal => !trun.rem_pow2(2).is_zero(),
+        };
+
+        if !away_from_zero {
+            trun
+        }
+
+        else if self.is_neg() {
+            trun.sub_i32(1)
+        }
+
+        else {
+            trun.add_i32(1)
+        }
+    }
+
+    /// It returns `(sign, whole, frac_numer)` where `self = sign * (whole + frac_numer / self.get_denom())`.\
+    /// `sign` is -1, 0 or 1, and `whole` and `frac_numer` are never negative. 7/3 -> (1, 2, 1), -7/3 -> (-1, 2, 1)
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn mixed_parts(&self) -> (BigInt, BigInt, BigInt) {
+        let (trun, frac) = self.truncate_and_frac();
+
+        let sign = if self.is_neg() {
+            BigInt::from_i32(-1)
+        } else if self.is_zero() {
+            BigInt::zero()
+        } else {
+            BigInt::one()
+        };
+
+        (sign, trun.abs(), frac.numer.abs())
+    }
+
+    /// It returns the denominators of a greedy (Fibonacci-Sylvester) unit fraction decomposition of `self`. 2/3 -> [2, 6] (1/2 + 1/6)\
+    /// 1 -> [1], and 0 -> []. It panics when `self` is negative or greater than 1.
+    pub fn to_egyptian_fraction(&self) -> Vec<BigInt> {
+        if self.is_neg() || self.gt_one() {
+            panic!("Attempt to get an Egyptian fraction of a number not in [0, 1]: {self:?}");
+        }
+
+        let mut result = vec![];
+        let mut remainder = self.clone();
+
+        // each step makes the numerator of `remainder` strictly smaller, so it always terminates
+        while !remainder.is_zero() {
+            // ceil(1 / remainder)
+            let unit_denom = remainder.denom.add(&remainder.numer).sub_i32(1).div(&remainder.numer);
+
+            remainder = Ratio::from_denom_and_numer(
+                remainder.denom.mul(&unit_denom),
+                remainder.numer.mul(&unit_denom).sub(&remainder.denom),
+            );
+            result.push(unit_denom);
+        }
+
+        result
+    }
+
     /// It returns a number between 0 and 1 (both exclusive).
     #[cfg(feature = "rand")]
     pub fn random() -> Self {
@@ -211,9 +518,22 @@ impl Ratio {
     }
 }
 
+// 2^exp
+fn pow2_i64(exp: i64) -> Ratio {
+    if exp < 0 {
+        Ratio::from_denom_and_numer(BigInt::pow2((-exp) as u32), BigInt::one())
+    }
+
+    else {
+        Ratio::from_bi(BigInt::pow2(exp as u32))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{Ratio, common_denom};
+    use crate::{Ratio, BigInt, UBigInt, bisect, cos_iter, common_denom, common_denominator, continued_product, geometric_series_infinite, powers_of, smoothstep, sum_balanced, weighted_mean, WeightedMeanError};
+    use crate::utils::rand_u32;
+    use super::sum_balanced_worker;
 
     #[test]
     fn round_test() {
@@ -227,6 +547,114 @@ mod tests {
         }
     }
 
+    #[test]
+    fn pow2_test() {
+        let samples = vec![
+            ("3", "2", "4"),
+            ("1/3", "1/4", "1/2"),
+            ("1", "1", "1"),
+            ("2", "2", "2"),
+            ("1/2", "1/2", "1/2"),
+            ("1/1024", "1/1024", "1/1024"),
+            ("7/8", "1/2", "1"),
+            ("9/8", "1", "2"),
+            ("1023/1024", "1/2", "1"),
+            ("1025/1024", "1", "2"),
+            ("1000000", "524288", "1048576"),
+            ("3/1000000", "1/524288", "1/262144"),
+        ];
+
+        for (n, floor, ceil) in samples.into_iter() {
+            let n = Ratio::from_ratio_string_radix(n, 10).unwrap();
+
+            assert_eq!(n.floor_pow2(), Ratio::from_ratio_string_radix(floor, 10).unwrap());
+            assert_eq!(n.ceil_pow2(), Ratio::from_ratio_string_radix(ceil, 10).unwrap());
+        }
+
+        let n = Ratio::from_denom_and_numer(BigInt::pow2(300), BigInt::pow2(100).add_i32(1));
+        assert_eq!(n.floor_pow2(), Ratio::from_denom_and_numer(BigInt::pow2(200), BigInt::one()));
+        assert_eq!(n.ceil_pow2(), Ratio::from_denom_and_numer(BigInt::pow2(199), BigInt::one()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn pow2_panic_test() {
+        let _ = Ratio::from_i32(-3).floor_pow2();
+    }
+
+    #[test]
+    fn continued_product_test() {
+        let half = Ratio::from_denom_and_numer_i32(2, 1);
+
+        assert_eq!(continued_product(powers_of(&half), 5), half.pow_i32(10));
+        assert_eq!(continued_product(powers_of(&half), 0), Ratio::one());
+
+        // (1 + 1/1) * (1 + 1/2) * ... * (1 + 1/n) = n + 1
+        let terms = (1..).map(|k| Ratio::from_denom_and_numer_i32(k, k + 1));
+        assert_eq!(continued_product(terms, 300), Ratio::from_i32(301));
+
+        // shorter than `n`
+        let terms = [3, -4, 5].map(|n| Ratio::from_denom_and_numer_i32(7, n));
+        assert_eq!(continued_product(terms.into_iter(), 10), Ratio::from_denom_and_numer_i32(343, -60));
+
+        let terms = [3, 0, 5].map(|n| Ratio::from_denom_and_numer_i32(7, n));
+        assert_eq!(continued_product(terms.into_iter(), 10), Ratio::zero());
+    }
+
+    #[test]
+    fn sum_balanced_test() {
+        assert_eq!(sum_balanced(&[]), Ratio::zero());
+        assert_eq!(sum_balanced(&[Ratio::from_i32(3)]), Ratio::from_i32(3));
+
+        let harmonic = (1..200).map(|n| Ratio::from_denom_and_numer_i32(n, 1)).collect::<Vec<_>>();
+        let alternating = (1..77).map(|n| Ratio::from_denom_and_numer_i32(n * 3, if n % 2 == 0 { n } else { -n - 1 })).collect::<Vec<_>>();
+
+        for values in [&harmonic[..], &harmonic[..3], &alternating[..], &alternating[..13]] {
+            let mut naive = Ratio::zero();
+
+            for v in values.iter() {
+                naive.add_mut(v);
+            }
+
+            assert_eq!(sum_balanced(values), naive);
+        }
+
+        // 1/2 + 1/3 + 1/5 + 1/7 + ...: every partial sum has a different denominator
+        let values = UBigInt::primes_below(1000).iter().map(
+            |p| Ratio::from_denom_and_numer(BigInt::from_ubi(p.clone(), false), BigInt::one())
+        ).collect::<Vec<_>>();
+
+        let mut naive = Ratio::zero();
+        let mut naive_len = 0;
+
+        for v in values.iter() {
+            naive.add_mut(v);
+            naive_len += naive.get_denom().len();
+        }
+
+        let mut balanced_len = 0;
+        let balanced = sum_balanced_worker(&values, |sum| { balanced_len += sum.get_denom().len(); });
+
+        assert_eq!(balanced, naive);
+        assert_eq!(sum_balanced(&values), balanced);
+        assert!(balanced_len * 5 < naive_len, "{balanced_len}, {naive_len}");
+    }
+
+    #[test]
+    fn round_even_test() {
+        let samples = vec![
+            ("0.5", 0), ("1.5", 2), ("2.5", 2), ("-0.5", 0),
+            ("-1.5", -2), ("-2.5", -2), ("3.5", 4),
+            ("0.4", 0), ("0.6", 1), ("2.7", 3), ("-2.7", -3), ("-2.3", -2), ("4.0", 4),
+        ];
+
+        for (n, rounded) in samples.into_iter() {
+            let n = Ratio::from_string(n).unwrap();
+            assert_eq!(n.round_even_bi(), BigInt::from_i32(rounded));
+            assert_eq!(n.round_even(), Ratio::from_i32(rounded));
+        }
+    }
+
     #[test]
     fn frac_trunc_floor_test() {
         let samples = vec![
@@ -254,6 +682,122 @@ mod tests {
         }
     }
 
+    #[test]
+    fn mixed_parts_test() {
+        let samples = vec![
+            ((3, 2), (1, 0, 2)),
+            ((3, 7), (1, 2, 1)),
+            ((3, -7), (-1, 2, 1)),
+            ((3, -2), (-1, 0, 2)),
+            ((1, 5), (1, 5, 0)),
+            ((1, -5), (-1, 5, 0)),
+            ((1, 0), (0, 0, 0)),
+        ];
+
+        for ((denom, numer), (sign, whole, frac_numer)) in samples.into_iter() {
+            let n = Ratio::from_denom_and_numer_i32(denom, numer);
+            let (sign_, whole_, frac_numer_) = n.mixed_parts();
+
+            assert_eq!(sign_, BigInt::from_i32(sign));
+            assert_eq!(whole_, BigInt::from_i32(whole));
+            assert_eq!(frac_numer_, BigInt::from_i32(frac_numer));
+
+            // reassemble
+            assert_eq!(
+                Ratio::from_denom_and_numer(n.get_denom(), whole_.mul(&n.get_denom()).add(&frac_numer_).mul(&sign_)),
+                n,
+            );
+        }
+
+        let n = Ratio::from_string("-12345678901234567890.125").unwrap();
+        let (sign, whole, frac_numer) = n.mixed_parts();
+        assert_eq!(sign, BigInt::from_i32(-1));
+        assert_eq!(whole, BigInt::from_string("12345678901234567890").unwrap());
+        assert_eq!(frac_numer, BigInt::one());
+        assert_eq!(n.get_denom(), BigInt::from_i32(8));
+    }
+
+    #[test]
+    fn egyptian_fraction_test() {
+        let samples = vec![
+            ((3, 2), vec![2, 6]),
+            ((5, 4), vec![2, 4, 20]),
+            ((13, 4), vec![4, 18, 468]),
+            ((7, 3), vec![3, 11, 231]),
+            ((2, 1), vec![2]),
+            ((1, 1), vec![1]),
+            ((1, 0), vec![]),
+        ];
+
+        for ((denom, numer), denoms) in samples.into_iter() {
+            assert_eq!(
+                Ratio::from_denom_and_numer_i32(denom, numer).to_egyptian_fraction(),
+                denoms.into_iter().map(BigInt::from_i32).collect::<Vec<_>>(),
+            );
+        }
+
+        let mut seed = 7u32;
+
+        for _ in 0..64 {
+            let denom = (rand_u32(&mut seed) >> 16) % 200 + 1;
+            let numer = (rand_u32(&mut seed) >> 16) % (denom + 1);
+
+            let n = Ratio::from_denom_and_numer_i32(denom as i32, numer as i32);
+            let denoms = n.to_egyptian_fraction();
+            let mut sum = Ratio::zero();
+
+            for (i, d) in denoms.iter().enumerate() {
+                // the greedy algorithm always generates strictly increasing denominators
+                if i > 0 {
+                    assert!(d.gt(&denoms[i - 1]));
+                }
+
+                sum.add_mut(&Ratio::from_denom_and_numer(d.clone(), BigInt::one()));
+            }
+
+            assert_eq!(sum, n);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn egyptian_fraction_panic_test() {
+        let _ = Ratio::from_denom_and_numer_i32(2, 3).to_egyptian_fraction();
+    }
+
+    #[test]
+    fn checked_div_test() {
+        let a = Ratio::from_denom_and_numer_i32(3, 2);
+        let b = Ratio::from_denom_and_numer_i32(5, -4);
+
+        assert_eq!(a.checked_div(&b), Some(a.div(&b)));
+        assert_eq!(a.checked_div(&Ratio::zero()), None);
+        assert_eq!(Ratio::zero().checked_div(&a), Some(Ratio::zero()));
+        assert_eq!(b.checked_reci(), Some(b.reci()));
+        assert_eq!(Ratio::zero().checked_reci(), None);
+
+        // 1 / (1 / (x - 2) - 1 / (x - 3) + 1)
+        let chain = |x: &Ratio| -> Option<Ratio> {
+            let u = x.sub(&Ratio::from_i32(2)).checked_reci()?;
+            let v = x.sub(&Ratio::from_i32(3)).checked_reci()?;
+
+            u.sub(&v).add(&Ratio::one()).checked_reci()
+        };
+
+        assert_eq!(chain(&Ratio::from_i32(4)), Some(Ratio::from_denom_and_numer_i32(1, 2)));
+        assert_eq!(chain(&Ratio::from_i32(2)), None);
+        assert_eq!(chain(&Ratio::from_i32(3)), None);
+
+        // 10 / 5 - 2 = 0, so the chain stops there
+        assert_eq!(
+            Some(Ratio::from_i32(10))
+                .and_then(|n| n.checked_div(&Ratio::from_i32(5)))
+                .and_then(|n| n.sub(&Ratio::from_i32(2)).checked_reci())
+                .map(|n| n.add(&Ratio::one())),
+            None,
+        );
+    }
+
     #[test]
     fn common_denom_test() {
         for denom1 in 1..11 {
@@ -279,4 +823,159 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn common_denominator_test() {
+        let ratios = vec![
+            Ratio::from_denom_and_numer_i32(2, 1),
+            Ratio::from_denom_and_numer_i32(3, -2),
+            Ratio::from_denom_and_numer_i32(4, 3),
+            Ratio::from_denom_and_numer_i32(6, 5),
+            Ratio::from_i32(7),
+            Ratio::zero(),
+        ];
+
+        let (denom, numers) = common_denominator(&ratios);
+        assert_eq!(denom, BigInt::from_i32(12));
+        assert_eq!(numers, vec![6, -8, 9, 10, 84, 0].into_iter().map(BigInt::from_i32).collect::<Vec<BigInt>>());
+
+        for (r, n) in ratios.iter().zip(numers.iter()) {
+            assert_eq!(r, &Ratio::from_denom_and_numer(denom.clone(), n.clone()));
+        }
+
+        // 1 + 1/2 + ... + 1/30
+        let harmonic = (1..31).map(|n| Ratio::from_denom_and_numer_i32(n, 1)).collect::<Vec<Ratio>>();
+        let (denom, numers) = common_denominator(&harmonic);
+        let mut numer_sum = BigInt::zero();
+        let mut sum = Ratio::zero();
+
+        for (r, n) in harmonic.iter().zip(numers.iter()) {
+            numer_sum.add_mut(n);
+            sum.add_mut(r);
+        }
+
+        assert_eq!(Ratio::from_denom_and_numer(denom, numer_sum), sum);
+        assert_eq!(common_denominator(&[]), (BigInt::one(), vec![]));
+    }
+
+    #[test]
+    fn bisect_test() {
+        let square_minus_2 = |x: &Ratio| x.mul(x).sub_i32(2);
+        let root = bisect(square_minus_2, &Ratio::one(), &Ratio::from_i32(2), 40);
+
+        // |root - sqrt(2)| < 2^-41
+        assert!(root.mul(&root).sub_i32(2).abs().lt(&Ratio::from_denom_and_numer(BigInt::from_i64(1 << 39), BigInt::one())));
+        assert_eq!("1.41421356", root.to_approx_string(10));
+
+        // the order of `lo` and `hi` doesn't matter
+        assert_eq!(bisect(square_minus_2, &Ratio::from_i32(2), &Ratio::one(), 40), root);
+
+        // exact roots
+        assert_eq!(bisect(|x| x.sub_i32(3), &Ratio::zero(), &Ratio::from_i32(4), 10), Ratio::from_i32(3));
+        assert_eq!(bisect(|x| x.sub_i32(4), &Ratio::zero(), &Ratio::from_i32(4), 10), Ratio::from_i32(4));
+        assert_eq!(bisect(|x| x.sub_i32(1), &Ratio::zero(), &Ratio::from_i32(4), 0), Ratio::from_i32(2));
+
+        // x = cos(x)
+        let dottie = bisect(|x| x.sub(&cos_iter(x, 8)), &Ratio::zero(), &Ratio::one(), 24);
+        assert_eq!("0.73908", dottie.to_approx_string(7));
+    }
+
+    #[test]
+    #[should_panic]
+    fn bisect_panic_test() {
+        bisect(|x| x.mul(x).add_i32(1), &Ratio::from_i32(-1), &Ratio::one(), 10);
+    }
+
+    #[test]
+    fn geometric_series_infinite_test() {
+        let half = Ratio::from_denom_and_numer_i32(2, 1);
+
+        assert_eq!(geometric_series_infinite(&Ratio::one(), &half), Some(Ratio::from_i32(2)));
+        assert_eq!(geometric_series_infinite(&Ratio::one(), &half.neg()), Some(Ratio::from_denom_and_numer_i32(3, 2)));
+        assert_eq!(geometric_series_infinite(&Ratio::from_i32(3), &Ratio::zero()), Some(Ratio::from_i32(3)));
+
+        // 0.999... = 9/10 + 9/100 + ... = 1
+        assert_eq!(
+            geometric_series_infinite(&Ratio::from_denom_and_numer_i32(10, 9), &Ratio::from_denom_and_numer_i32(10, 1)),
+            Some(Ratio::one()),
+        );
+
+        // partial sums approach the result
+        let a = Ratio::from_denom_and_numer_i32(7, 5);
+        let r = Ratio::from_denom_and_numer_i32(3, -2);
+        let sum = geometric_series_infinite(&a, &r).unwrap();
+        let partial = powers_of(&r).take(64).fold(Ratio::zero(), |acc, p| acc.add(&p.mul(&a)));
+        assert!(partial.sub(&sum).abs().lt(&r.abs().pow_i32(60)));
+
+        for r in [Ratio::one(), Ratio::from_i32(-1), Ratio::from_i32(2), Ratio::from_denom_and_numer_i32(2, -3)] {
+            assert_eq!(geometric_series_infinite(&Ratio::one(), &r), None);
+        }
+    }
+
+    #[test]
+    fn powers_of_test() {
+        let samples = [
+            Ratio::from_denom_and_numer_i32(3, 2),
+            Ratio::from_denom_and_numer_i32(7, -5),
+            Ratio::from_i32(10),
+            Ratio::one(),
+            Ratio::zero(),
+        ];
+
+        for base in samples.iter() {
+            for (n, power) in powers_of(base).take(40).enumerate() {
+                assert_eq!(power, base.pow_i32(n as i32));
+            }
+        }
+
+        assert_eq!(
+            powers_of(&Ratio::from_i32(2)).take(5).collect::<Vec<Ratio>>(),
+            vec![1, 2, 4, 8, 16].into_iter().map(Ratio::from_i32).collect::<Vec<Ratio>>(),
+        );
+    }
+
+    #[test]
+    fn smoothstep_test() {
+        let edge0 = Ratio::from_i32(2);
+        let edge1 = Ratio::from_i32(6);
+
+        assert_eq!(smoothstep(&edge0, &edge1, &Ratio::from_i32(-100)), Ratio::zero());
+        assert_eq!(smoothstep(&edge0, &edge1, &Ratio::from_i32(2)), Ratio::zero());
+        assert_eq!(smoothstep(&edge0, &edge1, &Ratio::from_i32(4)), Ratio::from_denom_and_numer_i32(2, 1));
+        assert_eq!(smoothstep(&edge0, &edge1, &Ratio::from_i32(6)), Ratio::one());
+        assert_eq!(smoothstep(&edge0, &edge1, &Ratio::from_i32(100)), Ratio::one());
+
+        // t = 1/4 -> 3/16 - 2/64 = 5/32
+        assert_eq!(smoothstep(&edge0, &edge1, &Ratio::from_i32(3)), Ratio::from_denom_and_numer_i32(32, 5));
+
+        // symmetric: smoothstep(t) + smoothstep(1 - t) = 1
+        let x = Ratio::from_denom_and_numer_i32(7, 20);
+        let x_mirror = edge0.add(&edge1).sub(&x);
+        assert_eq!(smoothstep(&edge0, &edge1, &x).add(&smoothstep(&edge0, &edge1, &x_mirror)), Ratio::one());
+
+        // reversed edges
+        assert_eq!(smoothstep(&edge1, &edge0, &Ratio::from_i32(100)), Ratio::zero());
+        assert_eq!(smoothstep(&edge1, &edge0, &Ratio::from_i32(-100)), Ratio::one());
+    }
+
+    #[test]
+    fn weighted_mean_test() {
+        let values = vec![Ratio::from_i32(1), Ratio::from_i32(2), Ratio::from_i32(4)];
+        let weights = vec![Ratio::from_i32(3), Ratio::from_i32(1), Ratio::from_denom_and_numer_i32(2, 1)];
+
+        // (3 + 2 + 2) / 4.5
+        assert_eq!(weighted_mean(&values, &weights).unwrap(), Ratio::from_denom_and_numer_i32(9, 14));
+
+        let values = vec![Ratio::from_i32(-3), Ratio::from_denom_and_numer_i32(3, 1), Ratio::from_i32(7), Ratio::from_i32(5)];
+        let weights = vec![Ratio::from_denom_and_numer_i32(5, 2); 4];
+
+        assert_eq!(
+            weighted_mean(&values, &weights).unwrap(),
+            values.iter().fold(Ratio::zero(), |acc, v| acc.add(v)).div_i32(4),
+        );
+
+        assert_eq!(weighted_mean(&values, &weights[0..3]), Err(WeightedMeanError::LengthMismatch { values: 4, weights: 3 }));
+        assert_eq!(weighted_mean(&values[0..2], &[Ratio::from_i32(1), Ratio::from_i32(-1)]), Err(WeightedMeanError::ZeroTotalWeight));
+        assert_eq!(weighted_mean(&[], &[]), Err(WeightedMeanError::ZeroTotalWeight));
+    }
 }
@@ -1,26 +1,35 @@
-use crate::{Ratio, pi_iter};
-
-// TODO: when x ~= 1
+use crate::{Ratio, pi_iter, sqrt_iter};
 
+/// It returns `asin(x)`. It gets more accurate as `iter` gets bigger.\
+/// When `abs(x) > 1/4`, where the Taylor series converges slowly, it uses `asin(x) = atan(x / sqrt(1 - x^2))`.
+/// It panics when `abs(x) > 1`.
 pub fn asin_iter(x: &Ratio, iter: usize) -> Ratio {
-    let mut result = x.clone();
+    let x_sqr = x.mul(x);
 
-    if result.gt_one() {
+    if x_sqr.gt_one() {
         panic!("Math Domain Error: asin({x})");
     }
 
-    result.neg_mut();
+    // asin(1) = pi/2, asin(-1) = -pi/2
+    if x_sqr.eq_i32(1) {
+        let mut result = pi_iter(iter).div_i32(2);
 
-    if result.gt_one() {
-        result.neg_mut();
+        if x.is_neg() {
+            result.neg_mut();
+        }
 
-        panic!("Math Domain Error: asin({x})");
+        return result;
     }
 
-    result.neg_mut();
+    if x_sqr.mul_i32(16).gt_one() {
+        // `sqrt_iter` gains 16 bits per `iter`, while `atan_iter` gains at least 6 bits per `iter`
+        let denom = sqrt_iter(&Ratio::one().sub(&x_sqr), iter / 2);
+
+        return atan_iter(&x.div(&denom), iter);
+    }
 
+    let mut result = x.clone();
     let mut curr_coeff = Ratio::from_denom_and_numer_i32(6, 1);
-    let x_sqr = x.mul(x);
     let mut curr_x_coeff = x_sqr.mul(x);
 
     for i in 0..iter {
@@ -36,15 +45,20 @@ pub fn asin_iter(x: &Ratio, iter: usize) -> Ratio {
     result
 }
 
+/// It returns `acos(x)`, which is `pi/2 - asin(x)`. It gets more accurate as `iter` gets bigger.\
+/// It panics when `abs(x) > 1`.
 pub fn acos_iter(x: &Ratio, iter: usize) -> Ratio {
     pi_iter(iter).div_i32(2).sub(&asin_iter(x, iter))
 }
 
+/// It returns `atan(x)`. It gets more accurate as `iter` gets bigger.\
+/// It uses `atan(x) = pi/2 - atan(1/x)` and `atan(x) = pi/4 + atan((x - 1) / (x + 1))` so that the series always converges fast.
 // x - x^3/3 + x^5/5 - x^7/7 + ...
 pub fn atan_iter(x: &Ratio, iter: usize) -> Ratio {
     let mut result = x.clone();
     let mut is_neg = false;
     let mut is_reci = false;
+    let mut is_shifted = false;
 
     if result.is_neg() {
         result.neg_mut();
@@ -56,6 +70,13 @@ pub fn atan_iter(x: &Ratio, iter: usize) -> Ratio {
         is_reci = true;
     }
 
+    // the series converges very slowly when x ~= 1
+    // 1/2 < x <= 1 -> -1/3 < (x - 1) / (x + 1) <= 0
+    if result.gt(&Ratio::from_denom_and_numer_i32(2, 1)) {
+        result = result.sub_i32(1).div(&result.add_i32(1));
+        is_shifted = true;
+    }
+
     let x_sqr = result.mul(&result);
     let mut curr_x_coeff = x_sqr.mul(&result);
     let mut curr_coeff = 3;
@@ -72,9 +93,16 @@ pub fn atan_iter(x: &Ratio, iter: usize) -> Ratio {
 
     result.sub_mut(&curr_x_coeff.div_i32(curr_coeff));
 
-    if is_reci {
-        let pi = pi_iter(iter).div_i32(2);
-        result = pi.sub(&result);
+    if is_shifted || is_reci {
+        let pi = pi_iter(iter);
+
+        if is_shifted {
+            result.add_mut(&pi.div_i32(4));
+        }
+
+        if is_reci {
+            result = pi.div_i32(2).sub(&result);
+        }
     }
 
     if is_neg {
@@ -87,7 +115,7 @@ pub fn atan_iter(x: &Ratio, iter: usize) -> Ratio {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{sin_iter, cos_iter, tan_iter};
+    use crate::{sin_iter, cos_iter, tan_iter, sqrt_iter};
 
     #[test]
     fn atrigo_test() {
@@ -129,4 +157,74 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn asin_acos_test() {
+        let pi = pi_iter(64);
+        let tolerance = Ratio::from_string("1e-10").unwrap();
+        let half = Ratio::from_denom_and_numer_i32(2, 1);
+
+        // asin(1/2) = pi/6, acos(0) = pi/2
+        assert!(asin_iter(&half, 8).sub(&pi.div_i32(6)).abs().lt(&tolerance));
+        assert!(asin_iter(&half.neg(), 8).add(&pi.div_i32(6)).abs().lt(&tolerance));
+        assert!(acos_iter(&Ratio::zero(), 8).sub(&pi.div_i32(2)).abs().lt(&tolerance));
+        assert!(acos_iter(&half, 8).sub(&pi.div_i32(3)).abs().lt(&tolerance));
+        assert_eq!(asin_iter(&Ratio::zero(), 8), Ratio::zero());
+
+        // endpoints
+        assert!(asin_iter(&Ratio::one(), 8).sub(&pi.div_i32(2)).abs().lt(&tolerance));
+        assert!(asin_iter(&Ratio::from_i32(-1), 8).add(&pi.div_i32(2)).abs().lt(&tolerance));
+        assert!(acos_iter(&Ratio::one(), 8).abs().lt(&tolerance));
+        assert!(acos_iter(&Ratio::from_i32(-1), 8).sub(&pi).abs().lt(&tolerance));
+
+        // asin(sqrt(2)/2) = pi/4, and it's accurate even very close to 1
+        let sqrt2_half = sqrt_iter(&half, 8);
+        assert!(asin_iter(&sqrt2_half, 8).sub(&pi.div_i32(4)).abs().lt(&tolerance));
+
+        let near_one = Ratio::one().sub(&Ratio::from_string("1e-10").unwrap());
+        assert!(asin_iter(&near_one, 8).add(&acos_iter(&near_one, 8)).sub(&pi.div_i32(2)).abs().lt(&tolerance));
+        assert!(pi.div_i32(2).sub(&asin_iter(&near_one, 8)).lt(&Ratio::from_string("1.5e-5").unwrap()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn asin_domain_test() {
+        let _ = asin_iter(&Ratio::from_denom_and_numer_i32(100, 101), 4);
+    }
+
+    #[test]
+    fn atan_test() {
+        let pi = pi_iter(64);
+        let mut prev_err = Ratio::one();
+
+        // atan(1) = pi / 4
+        for iter in [1, 2, 4, 8, 16] {
+            let err = atan_iter(&Ratio::one(), iter).sub(&pi.div_i32(4)).abs();
+
+            assert!(err.leq(&prev_err));
+            prev_err = err;
+        }
+
+        assert!(prev_err.lt(&Ratio::from_string("1e-20").unwrap()));
+
+        // atan(-x) = -atan(x), atan(x) + atan(1/x) = pi / 2
+        for (denom, numer) in [(1, 2), (3, 1), (5, 4), (100, 99), (7, 100)] {
+            let x = Ratio::from_denom_and_numer_i32(denom, numer);
+
+            assert_eq!(atan_iter(&x.neg(), 12), atan_iter(&x, 12).neg());
+            assert!(atan_iter(&x, 12).add(&atan_iter(&x.reci(), 12)).sub(&pi.div_i32(2)).abs().lt(&Ratio::from_string("1e-12").unwrap()));
+        }
+
+        // tan(atan(x)) = x
+        for x in ["0.5", "0.99", "1.01", "-2", "1000"] {
+            let x = Ratio::from_string(x).unwrap();
+            let mut rad = atan_iter(&x, 16);
+            rad.shrink(3).unwrap();
+
+            // d tan(y) / dy = 1 + x^2
+            let tolerance = Ratio::from_string("1e-8").unwrap().mul(&x.mul(&x).add_i32(1));
+
+            assert!(tan_iter(&rad, 5).sub(&x).abs().lt(&tolerance));
+        }
+    }
 }
@@ -0,0 +1,457 @@
+use crate::{BigInt, Ratio, UBigInt, gcd_ubi};
+
+impl Ratio {
+
+    /// It returns the coefficients of the continued fraction of `self`. 43/19 = 2 + 1 / (3 + 1 / (1 + 1 / 4)) -> [2, 3, 1, 4]\
+    /// `Ratio` is exact, so the result is always finite.
+    pub fn continued_fraction(&self) -> Vec<BigInt> {
+        let mut result = vec![];
+        let mut curr = self.clone();
+
+        loop {
+            let a = curr.floor_bi();
+            curr.sub_bi_mut(&a);
+            result.push(a);
+
+            if curr.is_zero() {
+                break;
+            }
+
+            curr.reci_mut();
+        }
+
+        result
+    }
+
+    /// It returns the closest rational number to `self` whose denominator is at most `max_denom`. pi -> 355/113 when `max_denom` is 1000\
+    /// It uses the convergents of the continued fraction of `self`, and the semiconvergent right before the first convergent that exceeds `max_denom`.
+    /// It panics when `max_denom` is less than 1.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn approximate(&self, max_denom: &BigInt) -> Self {
+        if max_denom.lt_i32(1) {
+            panic!("Attempt to approximate a number with a non-positive denominator: {self:?}, max_denom: {max_denom:?}");
+        }
+
+        if self.denom.leq(max_denom) {
+            return self.clone();
+        }
+
+        let is_neg = self.is_neg();
+        let numer = self.numer.abs();
+        let denom = &self.denom;
+
+        // convergents: p0 / q0, p1 / q1
+        let (mut p0, mut q0, mut p1, mut q1) = (BigInt::zero(), BigInt::one(), BigInt::one(), BigInt::zero());
+        let (mut n, mut d) = (numer.clone(), denom.clone());
+
+        // `self.denom > max_denom`, so it breaks before `d` becomes 0
+        loop {
+            let a = n.div(&d);
+            let q2 = q0.add(&a.mul(&q1));
+
+            if q2.gt(max_denom) {
+                break;
+            }
+
+            let p2 = p0.add(&a.mul(&p1));
+            let r = n.sub(&a.mul(&d));
+
+            (p0, q0, p1, q1) = (p1, q1, p2, q2);
+            (n, d) = (d, r);
+        }
+
+        // semiconvergent
+        let k = max_denom.sub(&q0).div(&q1);
+        let p_semi = p0.add(&k.mul(&p1));
+        let q_semi = q0.add(&k.mul(&q1));
+
+        // |p / q - numer / denom| = |p * denom - numer * q| / (q * denom)
+        let err_conv = p1.mul(denom).sub(&numer.mul(&q1)).abs().mul(&q_semi);
+        let err_semi = p_semi.mul(denom).sub(&numer.mul(&q_semi)).abs().mul(&q1);
+
+        let mut result = if err_conv.leq(&err_semi) {
+            Ratio::from_denom_and_numer_raw(q1, p1)
+        } else {
+            Ratio::from_denom_and_numer_raw(q_semi, p_semi)
+        };
+
+        if is_neg {
+            result.neg_mut();
+        }
+
+        result
+    }
+
+    /// Inverse of `continued_fraction`. It returns 0 when `coeffs` is empty.
+    pub fn from_continued_fraction(coeffs: &[BigInt]) -> Self {
+        let Some(last) = coeffs.last() else {
+            return Ratio::zero();
+        };
+
+        // numer / denom = a_i + 1 / (numer' / denom')
+        let mut numer = last.clone();
+        let mut denom = BigInt::one();
+
+        for a in coeffs.iter().rev().skip(1) {
+            let next_numer = a.mul(&numer).add(&denom);
+            denom = numer;
+            numer = next_numer;
+        }
+
+        Ratio::from_denom_and_numer(denom, numer)
+    }
+}
+
+/// It returns the continued fraction of `sqrt(n)`: `(a0, [a1, a2, ..., ak])` where `[a1, ..., ak]` repeats forever.\
+/// If `n` is a perfect square, the repeating part is empty.
+pub fn sqrt_continued_fraction(n: &UBigInt) -> (BigInt, Vec<BigInt>) {
+    let a0 = n.sqrt();
+
+    if a0.mul(&a0).eq(n) {
+        return (BigInt::from_ubi(a0, false), vec![]);
+    }
+
+    let a0_double = a0.mul_u32(2);
+    let mut m = UBigInt::zero();
+    let mut d = UBigInt::one();
+    let mut a = a0.clone();
+    let mut period = vec![];
+
+    // sqrt(n) = a0 + 1 / (a1 + 1 / (a2 + ...))
+    // the period ends with 2 * a0
+    while !a.eq(&a0_double) {
+        m = d.mul(&a).sub(&m);
+        d = n.sub(&m.mul(&m)).div(&d);
+        a = a0.add(&m).div(&d);
+
+        period.push(BigInt::from_ubi(a.clone(), false));
+    }
+
+    (BigInt::from_ubi(a0, false), period)
+}
+
+/// It returns the `convergents`-th convergent of the continued fraction of `sqrt(n)`, which is the best rational approximation of `sqrt(n)` with such a small denominator.\
+/// The first convergent is `a0`: `sqrt_cf_approx(2, 1)` is 1, `sqrt_cf_approx(2, 2)` is 3/2 and `sqrt_cf_approx(2, 8)` is 577/408.\
+/// If `n` is a perfect square, it returns `sqrt(n)`. It panics when `convergents` is 0.
+pub fn sqrt_cf_approx(n: &UBigInt, convergents: usize) -> Ratio {
+    if convergents == 0 {
+        panic!("Attempt to get 0 convergents of sqrt({n})");
+    }
+
+    let (a0, period) = sqrt_continued_fraction(n);
+
+    // h_n / k_n
+    let (mut h_prev, mut h) = (BigInt::one(), a0);
+    let (mut k_prev, mut k) = (BigInt::zero(), BigInt::one());
+
+    for a in period.iter().cycle().take(convergents - 1) {
+        (h_prev, h) = (h.clone(), a.mul(&h).add(&h_prev));
+        (k_prev, k) = (k.clone(), a.mul(&k).add(&k_prev));
+    }
+
+    // Safety: `h_n` and `k_n` are always coprime
+    Ratio::from_denom_and_numer_raw(k, h)
+}
+
+/// It returns the fundamental solution `(x, y)` of `x^2 - d * y^2 = 1`.\
+/// It panics when `d` is a perfect square.
+pub fn solve_pell(d: &UBigInt) -> (UBigInt, UBigInt) {
+    let (a0, period) = sqrt_continued_fraction(d);
+
+    if period.is_empty() {
+        panic!("Pell's equation has no non-trivial solution when `d` is a perfect square: {d}");
+    }
+
+    let a0 = a0.to_ubi().unwrap();
+    let period = period.into_iter().map(|a| a.to_ubi().unwrap()).collect::<Vec<UBigInt>>();
+
+    // convergents: h_n / k_n
+    let (mut h_prev, mut h) = (UBigInt::one(), a0);
+    let (mut k_prev, mut k) = (UBigInt::zero(), UBigInt::one());
+
+    // it always ends within 2 periods
+    for a in period.iter().cycle() {
+        if h.mul(&h).eq(&d.mul(&k).mul(&k).add_u32(1)) {
+            break;
+        }
+
+        let h_next = a.mul(&h).add(&h_prev);
+        let k_next = a.mul(&k).add(&k_prev);
+
+        h_prev = h;
+        h = h_next;
+        k_prev = k;
+        k = k_next;
+    }
+
+    (h, k)
+}
+
+/// It finds `n / d` where `n = r * d (mod m)` and `abs(n), d < sqrt(m / 2)`. Such `n / d` is unique if exists.\
+/// It runs the extended Euclidean algorithm on `m` and `r`, and stops at the first remainder smaller than `sqrt(m / 2)`.
+/// It panics when `m` is 0.
+pub fn rational_reconstruct(r: &UBigInt, m: &UBigInt) -> Option<Ratio> {
+    if m.is_zero() {
+        panic!("Attempt to divide by zero: rational_reconstruct({r:?}, 0)");
+    }
+
+    // r_i = s_i * r (mod m)
+    let (mut r_prev, mut r_curr) = (m.clone(), r.rem(m));
+    let (mut s_prev, mut s_curr) = (BigInt::zero(), BigInt::one());
+
+    // 2 * r_i^2 < m
+    while !r_curr.mul(&r_curr).mul_u32(2).lt(m) {
+        let (q, r_next) = r_prev.div_rem(&r_curr);
+        let s_next = s_prev.sub(&s_curr.mul(&BigInt::from_ubi(q, false)));
+
+        r_prev = r_curr;
+        r_curr = r_next;
+        s_prev = s_curr;
+        s_curr = s_next;
+    }
+
+    let d = s_curr.abs().to_ubi().unwrap();
+
+    if !d.mul(&d).mul_u32(2).lt(m) || !gcd_ubi(&r_curr, &d).is_one() {
+        return None;
+    }
+
+    Some(Ratio::from_denom_and_numer(s_curr, BigInt::from_ubi(r_curr, false)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BigInt, Ratio, UBigInt, e_iter, pi_iter, rational_reconstruct, sqrt_cf_approx, sqrt_continued_fraction, solve_pell, sqrt_iter};
+    use crate::utils::rand_u32;
+
+    #[test]
+    fn approximate_test() {
+        let pi = pi_iter(64);
+        let samples = vec![
+            (1, 3, 1),
+            (7, 22, 7),
+            (100, 311, 99),
+            (113, 355, 113),
+            (1000, 355, 113),
+            (33200, 103993, 33102),
+        ];
+
+        for (max_denom, numer, denom) in samples.into_iter() {
+            assert_eq!(
+                pi.approximate(&BigInt::from_i32(max_denom)),
+                Ratio::from_denom_and_numer_i32(denom, numer),
+            );
+            assert_eq!(
+                pi.neg().approximate(&BigInt::from_i32(max_denom)),
+                Ratio::from_denom_and_numer_i32(denom, -numer),
+            );
+        }
+
+        // exact inputs
+        for (denom, numer) in [(1, 0), (1, 5), (7, -3), (1000, 999)] {
+            let n = Ratio::from_denom_and_numer_i32(denom, numer);
+            assert_eq!(n.approximate(&BigInt::from_i32(1000)), n);
+        }
+
+        // sqrt(2) -> 1393/985, e -> 1457/536 (a semiconvergent)
+        assert_eq!(sqrt_iter(&Ratio::from_i32(2), 8).approximate(&BigInt::from_i32(1000)), Ratio::from_denom_and_numer_i32(985, 1393));
+        assert_eq!(e_iter(40).approximate(&BigInt::from_i32(1000)), Ratio::from_denom_and_numer_i32(536, 1457));
+
+        // 0.1235 -> 1/8 (0.125), not 1/9 (0.111...)
+        assert_eq!(Ratio::from_string("0.1235").unwrap().approximate(&BigInt::from_i32(9)), Ratio::from_denom_and_numer_i32(8, 1));
+
+        // brute force
+        let mut seed = 12345u32;
+
+        for _ in 0..64 {
+            let r = rand_u32(&mut seed);
+            let n = Ratio::from_denom_and_numer_i32((r >> 8) as i32 + 1, (r >> 1) as i32 - (1 << 30));
+            let max_denom = (rand_u32(&mut seed) >> 24) as i32 + 1;
+            let approx = n.approximate(&BigInt::from_i32(max_denom));
+
+            assert!(approx.get_denom().leq(&BigInt::from_i32(max_denom)));
+
+            let err = approx.sub(&n).abs();
+
+            for denom in 1..(max_denom + 1) {
+                let candidate = n.mul_i32(denom).round().div_i32(denom);
+                assert!(err.leq(&candidate.sub(&n).abs()));
+            }
+        }
+    }
+
+    #[test]
+    fn rational_reconstruct_test() {
+        let p = UBigInt::from_u32(2).pow_u32(127).sub_u32(1);
+
+        // n / d -> n * d^-1 (mod p)
+        let residue = |n: i32, d: u32| {
+            let d_inv = UBigInt::from_u32(d).mod_inv(&p).unwrap();
+            let r = UBigInt::from_u32(n.unsigned_abs()).mul(&d_inv).rem(&p);
+
+            if n < 0 && !r.is_zero() { p.sub(&r) } else { r }
+        };
+
+        assert_eq!(
+            rational_reconstruct(&residue(3, 4), &p),
+            Some(Ratio::from_denom_and_numer_i32(4, 3)),
+        );
+
+        for (n, d) in [(0, 1), (1, 1), (-1, 1), (22, 7), (-355, 113), (1_000_000_007, 998_244_353), (-2, 1_000_000_000)] {
+            assert_eq!(
+                rational_reconstruct(&residue(n, d), &p),
+                Some(Ratio::from_denom_and_numer(BigInt::from_i64(d as i64), BigInt::from_i32(n))),
+            );
+        }
+
+        // the numerator is too big
+        assert_eq!(rational_reconstruct(&UBigInt::from_u32(5), &UBigInt::from_u32(7)), None);
+        assert_eq!(rational_reconstruct(&UBigInt::from_u32(1), &UBigInt::from_u32(7)), Some(Ratio::one()));
+        assert_eq!(rational_reconstruct(&p.sub_u32(1), &p), Some(Ratio::from_i32(-1)));
+    }
+
+    #[test]
+    fn continued_fraction_test() {
+        let samples = vec![
+            (113, 355, vec![3, 7, 16]),
+            (19, 43, vec![2, 3, 1, 4]),
+            (1, 0, vec![0]),
+            (1, 7, vec![7]),
+            (7, 1, vec![0, 7]),
+            (7, -1, vec![-1, 1, 6]),
+            (113, -355, vec![-4, 1, 6, 16]),
+        ];
+
+        for (denom, numer, coeffs) in samples.into_iter() {
+            let n = Ratio::from_denom_and_numer_i32(denom, numer);
+            let coeffs = coeffs.into_iter().map(BigInt::from_i32).collect::<Vec<_>>();
+
+            assert_eq!(n.continued_fraction(), coeffs);
+            assert_eq!(Ratio::from_continued_fraction(&coeffs), n);
+        }
+
+        assert_eq!(Ratio::from_continued_fraction(&[]), Ratio::zero());
+
+        // consecutive Fibonacci numbers: [1; 1, 1, ..., 2]
+        let fib = Ratio::from_denom_and_numer(BigInt::from_ubi(UBigInt::fibonacci(200), false), BigInt::from_ubi(UBigInt::fibonacci(201), false));
+        let coeffs = fib.continued_fraction();
+        assert_eq!(coeffs.len(), 199);
+        assert_eq!(coeffs[198], BigInt::from_i32(2));
+        assert!(coeffs[..198].iter().all(|a| a.is_one()));
+        assert_eq!(Ratio::from_continued_fraction(&coeffs), fib);
+
+        let mut seed = 12345u32;
+
+        for _ in 0..64 {
+            let denom = (rand_u32(&mut seed) >> 8) as i32 + 1;
+            let numer = (rand_u32(&mut seed) >> 1) as i32 - (1 << 30);
+            let n = Ratio::from_denom_and_numer_i32(denom, numer);
+
+            assert_eq!(Ratio::from_continued_fraction(&n.continued_fraction()), n);
+        }
+    }
+
+    #[test]
+    fn sqrt_continued_fraction_test() {
+        let samples = vec![
+            (2, 1, vec![2]),
+            (3, 1, vec![1, 2]),
+            (7, 2, vec![1, 1, 1, 4]),
+            (13, 3, vec![1, 1, 1, 1, 6]),
+            (19, 4, vec![2, 1, 3, 1, 2, 8]),
+            (31, 5, vec![1, 1, 3, 5, 3, 1, 1, 10]),
+            (16, 4, vec![]),
+            (0, 0, vec![]),
+        ];
+
+        for (n, a0, period) in samples.into_iter() {
+            assert_eq!(
+                sqrt_continued_fraction(&UBigInt::from_u32(n)),
+                (BigInt::from_i32(a0), period.into_iter().map(BigInt::from_i32).collect::<Vec<_>>()),
+            );
+        }
+
+        // sqrt(2^64 + 1) = [2^32; 2^33]
+        assert_eq!(
+            sqrt_continued_fraction(&UBigInt::from_u128((1 << 64) + 1)),
+            (BigInt::from_i64(1 << 32), vec![BigInt::from_i64(1 << 33)]),
+        );
+    }
+
+    #[test]
+    fn sqrt_cf_approx_test() {
+        let two = UBigInt::from_u32(2);
+        let answers = [(1, 1), (3, 2), (7, 5), (17, 12), (41, 29), (99, 70), (239, 169), (577, 408)];
+
+        for (i, (numer, denom)) in answers.into_iter().enumerate() {
+            assert_eq!(sqrt_cf_approx(&two, i + 1), Ratio::from_denom_and_numer_i32(denom, numer));
+        }
+
+        // it's more accurate than a short `sqrt_iter`, and the denominator is much smaller
+        let two_rat = Ratio::from_i32(2);
+        let approx = sqrt_cf_approx(&two, 13);
+        let approx_iter = sqrt_iter(&two_rat, 0);
+
+        assert!(approx.mul(&approx).sub(&two_rat).abs().lt(&approx_iter.mul(&approx_iter).sub(&two_rat).abs()));
+        assert!(approx.get_denom().lt(&approx_iter.get_denom()));
+
+        for n in [3, 7, 13, 19, 31, 1000, 65537] {
+            let n = UBigInt::from_u32(n);
+            let (a0, period) = sqrt_continued_fraction(&n);
+            let mut terms = vec![a0];
+
+            for k in 1..20 {
+                let approx = sqrt_cf_approx(&n, k);
+                assert_eq!(approx, Ratio::from_continued_fraction(&terms));
+                terms.push(period[(k - 1) % period.len()].clone());
+
+                // convergents alternate around sqrt(n)
+                let error = approx.mul(&approx).sub(&Ratio::from_ubi(n.clone()));
+                assert_eq!(error.is_neg(), k % 2 == 1);
+            }
+        }
+
+        // perfect squares
+        assert_eq!(sqrt_cf_approx(&UBigInt::from_u32(144), 5), Ratio::from_i32(12));
+        assert_eq!(sqrt_cf_approx(&UBigInt::zero(), 1), Ratio::zero());
+    }
+
+    #[test]
+    #[should_panic]
+    fn sqrt_cf_approx_panic_test() {
+        let _ = sqrt_cf_approx(&UBigInt::from_u32(2), 0);
+    }
+
+    #[test]
+    fn pell_test() {
+        let samples = vec![
+            (2, 3, 2),
+            (3, 2, 1),
+            (5, 9, 4),
+            (7, 8, 3),
+            (13, 649, 180),
+            (61, 1766319049, 226153980),
+        ];
+
+        for (d, x, y) in samples.into_iter() {
+            assert_eq!(solve_pell(&UBigInt::from_u32(d)), (UBigInt::from_u64(x), UBigInt::from_u64(y)));
+        }
+
+        for d in 2..200 {
+            let d = UBigInt::from_u32(d);
+
+            if d.sqrt().mul(&d.sqrt()).eq(&d) {
+                continue;
+            }
+
+            let (x, y) = solve_pell(&d);
+            assert_eq!(x.mul(&x), d.mul(&y).mul(&y).add_u32(1));
+        }
+
+        // d = 991 has a famously large fundamental solution
+        let d = UBigInt::from_u32(991);
+        let (x, y) = solve_pell(&d);
+        assert_eq!(x, UBigInt::from_string("379516400906811930638014896080").unwrap());
+        assert_eq!(x.mul(&x), d.mul(&y).mul(&y).add_u32(1));
+    }
+}
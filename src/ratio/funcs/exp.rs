@@ -1,4 +1,4 @@
-use crate::{Ratio, e_iter};
+use crate::{BigInt, Ratio, e_iter};
 
 /// It returns `e^x`. It gets more accurate as `iter` gets bigger.
 pub fn exp_iter(x: &Ratio, iter: usize) -> Ratio {
@@ -34,9 +34,79 @@ pub fn exp_iter(x: &Ratio, iter: usize) -> Ratio {
     result
 }
 
+/// It returns `e^x`, like `exp_iter`, but it rounds the intermediate results so that their denominators never exceed `2^max_denom_bits`.\
+/// It's much cheaper than `exp_iter` when `iter` is big, but it's a little less accurate.
+/// `abs(exp_iter_bounded(x) - exp_iter(x))` is at most `max(1, e^x) * (16 * iter + abs(x) + 16) / 2^max_denom_bits`.
+pub fn exp_iter_bounded(x: &Ratio, iter: usize, max_denom_bits: u32) -> Ratio {
+    let (trun, mut frac) = x.truncate_and_frac();
+    frac = bound_denom(&frac, max_denom_bits);
+
+    let mut result = Ratio::one();
+    let mut iterator = frac.clone();
+    let mut inverse_iter = false;
+    let e_approx = bound_denom(&e_iter(iter), max_denom_bits);
+
+    if iterator.geq(&Ratio::from_denom_and_numer_i32(10, 9)) {
+        iterator = Ratio::one().sub(&iterator);
+        frac = iterator.clone();
+        inverse_iter = true;
+    }
+
+    for n in 2..(iter as i32 + 2) {
+        result.add_mut(&iterator);
+        iterator.mul_mut(&frac);
+        iterator.div_i32_mut(n);
+        iterator = bound_denom(&iterator, max_denom_bits);
+    }
+
+    if inverse_iter {
+        result = bound_denom(&e_approx.div(&result), max_denom_bits);
+    }
+
+    let e_pow = bound_denom(&e_approx.pow_i32(trun.to_i32().unwrap()), max_denom_bits);
+    result.mul_mut(&e_pow);
+
+    bound_denom(&result, max_denom_bits)
+}
+
+// it truncates `x` to a multiple of `2^-bits` if its denominator is bigger than `2^bits`
+fn bound_denom(x: &Ratio, bits: u32) -> Ratio {
+    let max_denom = BigInt::pow2(bits);
+
+    if x.denom.leq(&max_denom) {
+        return x.clone();
+    }
+
+    let numer = x.numer.mul(&max_denom).div(&x.denom);
+
+    Ratio::from_denom_and_numer(max_denom, numer)
+}
+
+/// It returns `e^x - 1`. It gets more accurate as `iter` gets bigger.\
+/// When `x` is close to 0, it converges much faster than `exp_iter(x) - 1`.
+pub fn exp_m1_iter(x: &Ratio, iter: usize) -> Ratio {
+    // the series below is only good for small `x`
+    if x.abs().geq(&Ratio::from_denom_and_numer_i32(2, 1)) {
+        return exp_iter(x, iter).sub_i32(1);
+    }
+
+    // e^x - 1 = sigma{n=1}{inf} x^n / n!
+    let mut result = Ratio::zero();
+    let mut iterator = x.clone();
+
+    for n in 2..(iter as i32 + 2) {
+        result.add_mut(&iterator);
+        iterator.mul_mut(x);
+        iterator.div_i32_mut(n);
+    }
+
+    result
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{Ratio, exp_iter, ln2_iter};
+    use crate::{BigInt, Ratio, exp_iter, exp_iter_bounded, exp_m1_iter, ln2_iter};
+    use crate::utils::are_close;
 
     #[test]
     fn exp_test() {
@@ -49,4 +119,51 @@ mod tests {
         assert_eq!("20.08553", exp_iter(&Ratio::from_i32(3), 15).to_approx_string(8));
         assert_eq!("10", exp_iter(&ln2_iter(8).mul_i32(70777).div_i32(21306), 8).to_approx_string(10));
     }
+
+    #[test]
+    fn exp_m1_test() {
+        for x in ["0.3", "-0.3", "0.001", "-0.0001", "0.49", "0.5", "-0.7", "2.5", "-3"] {
+            let x = Ratio::from_string(x).unwrap();
+
+            assert!(are_close(&exp_m1_iter(&x, 16), &exp_iter(&x, 16).sub_i32(1), 1e-12));
+        }
+
+        // for a tiny `x`, only a few terms are needed
+        let tiny = Ratio::from_string("1e-20").unwrap();
+        let accurate = exp_m1_iter(&tiny, 8);
+
+        for x in [tiny.clone(), tiny.neg()] {
+            assert!(are_close(&exp_m1_iter(&x, 2), &exp_m1_iter(&x, 8), 1e-15));
+            assert!(are_close(&exp_m1_iter(&x, 2), &exp_iter(&x, 16).sub_i32(1), 1e-15));
+        }
+
+        assert!(are_close(&exp_m1_iter(&tiny, 1), &accurate, 1e-15));
+        assert_eq!(exp_m1_iter(&Ratio::zero(), 4), Ratio::zero());
+    }
+
+    #[test]
+    fn exp_iter_bounded_test() {
+        for x in ["0", "0.3", "-0.3", "0.95", "1", "-1", "2.5", "-3.7", "7.25", "-12.5"] {
+            let x = Ratio::from_string(x).unwrap();
+
+            for (iter, bits) in [(8, 32), (16, 64), (24, 128), (40, 256)] {
+                let accurate = exp_iter(&x, iter);
+                let bounded = exp_iter_bounded(&x, iter, bits);
+
+                assert!(bounded.get_denom().leq(&BigInt::pow2(bits)));
+
+                // the documented bound: max(1, e^x) * (16 * iter + abs(x) + 16) / 2^bits
+                let bound = if accurate.gt(&Ratio::one()) { accurate.clone() } else { Ratio::one() }
+                    .mul(&x.abs().add_i32(16 * iter as i32 + 16))
+                    .div(&Ratio::from_bi(BigInt::pow2(bits)));
+
+                assert!(bounded.sub(&accurate).abs().leq(&bound));
+            }
+        }
+
+        // denominators of `exp_iter` grow very fast, but `exp_iter_bounded` doesn't
+        let x = Ratio::from_string("0.123").unwrap();
+        assert!(exp_iter(&x, 64).get_denom().log2().gt(&BigInt::from_i32(256)));
+        assert!(exp_iter_bounded(&x, 64, 64).get_denom().leq(&BigInt::pow2(64)));
+    }
 }
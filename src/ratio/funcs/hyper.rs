@@ -1,13 +1,89 @@
-use crate::Ratio;
+use crate::{Ratio, exp_m1_iter};
 
+// every function below is computed from a single `u = e^abs(x) - 1`
+// `e^-x` is `1 / e^x`, which is exact, so it doesn't lose precision even when `e^-x` is much smaller than `e^x`
+// `exp_m1_iter` is accurate near 0, where `e^x - e^-x` would cancel out
+fn exp_m1_abs(x: &Ratio, iter: usize) -> Ratio {
+    exp_m1_iter(&x.abs(), iter)
+}
+
+/// It returns `sinh(x) = (e^x - e^-x) / 2`. It gets more accurate as `iter` gets bigger.
 pub fn sinh_iter(x: &Ratio, iter: usize) -> Ratio {
-    todo!()
+    let u = exp_m1_abs(x, iter);
+
+    // e^x - e^-x = u + u / (u + 1)
+    let mut result = u.add(&u.div(&u.add_i32(1))).div_i32(2);
+
+    if x.is_neg() {
+        result.neg_mut();
+    }
+
+    result
 }
 
+/// It returns `cosh(x) = (e^x + e^-x) / 2`. It gets more accurate as `iter` gets bigger.
 pub fn cosh_iter(x: &Ratio, iter: usize) -> Ratio {
-    todo!()
+    let e = exp_m1_abs(x, iter).add_i32(1);
+
+    e.add(&e.reci()).div_i32(2)
 }
 
+/// It returns `tanh(x) = (e^x - e^-x) / (e^x + e^-x)`. It gets more accurate as `iter` gets bigger.\
+/// It computes the exponential only once.
 pub fn tanh_iter(x: &Ratio, iter: usize) -> Ratio {
-    todo!()
+    let u = exp_m1_abs(x, iter);
+    let e = u.add_i32(1);
+
+    // (e - 1/e) / (e + 1/e) = u * (u + 2) / (e^2 + 1)
+    let mut result = u.mul(&u.add_i32(2)).div(&e.mul(&e).add_i32(1));
+
+    if x.is_neg() {
+        result.neg_mut();
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Ratio, cosh_iter, exp_iter, sinh_iter, tanh_iter};
+    use crate::utils::are_close;
+
+    #[test]
+    fn hyper_test() {
+        assert_eq!(cosh_iter(&Ratio::zero(), 8), Ratio::one());
+        assert_eq!(sinh_iter(&Ratio::zero(), 8), Ratio::zero());
+        assert_eq!(tanh_iter(&Ratio::zero(), 8), Ratio::zero());
+
+        assert_eq!("1.1752011936", sinh_iter(&Ratio::one(), 16).to_approx_string(12));
+        assert_eq!("1.5430806348", cosh_iter(&Ratio::one(), 16).to_approx_string(12));
+        assert_eq!("0.7615941559", tanh_iter(&Ratio::one(), 16).to_approx_string(12));
+        assert_eq!("-3.626860407", sinh_iter(&Ratio::from_i32(-2), 16).to_approx_string(12));
+
+        for x in ["0.001", "0.3", "1.5", "-0.7", "4", "-10"] {
+            let x = Ratio::from_string(x).unwrap();
+            let sinh = sinh_iter(&x, 16);
+            let cosh = cosh_iter(&x, 16);
+            let tanh = tanh_iter(&x, 16);
+
+            // cosh^2 - sinh^2 = 1
+            assert!(are_close(&cosh.mul(&cosh).sub(&sinh.mul(&sinh)), &Ratio::one(), 1e-12));
+            assert!(are_close(&tanh, &sinh.div(&cosh), 1e-12));
+            assert_eq!(sinh_iter(&x.neg(), 16), sinh.neg());
+            assert_eq!(cosh_iter(&x.neg(), 16), cosh);
+            assert_eq!(tanh_iter(&x.neg(), 16), tanh.neg());
+            assert!(are_close(&sinh.add(&cosh), &exp_iter(&x, 16), 1e-12));
+        }
+
+        // no catastrophic cancellation near 0: sinh(x) ~= x
+        let tiny = Ratio::from_string("1e-30").unwrap();
+        assert!(are_close(&sinh_iter(&tiny, 4), &tiny, 1e-20));
+        assert!(are_close(&tanh_iter(&tiny, 4), &tiny, 1e-20));
+
+        // it saturates to 1 and -1
+        let tanh_big = tanh_iter(&Ratio::from_i32(20), 16);
+        assert!(tanh_big.lt_one());
+        assert!(Ratio::one().sub(&tanh_big).lt(&Ratio::from_string("1e-16").unwrap()));
+        assert!(tanh_iter(&Ratio::from_i32(-20), 16).gt_i32(-1));
+    }
 }
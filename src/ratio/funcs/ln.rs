@@ -13,7 +13,8 @@ pub fn ln_iter(x: &Ratio, iter: usize) -> Ratio {
     // ln(x) = ln(1 + a) = sum{k=1}{inf} -(-a)^k/k = a - a^2/2 + a^3/3 - a^4/4...
     // it's best when a is close to 0 -> log_2(1 + a) = log_2(x) is close to 0
     // approximation of log_2 is very easily calculated: log2_accurate
-    let log2_approx = x.numer.log2_accurate().sub(&x.denom.log2_accurate()).shift_right(1).to_i64().unwrap();
+    // it rounds log_2(x) to the nearest integer, so that 1/sqrt(2) < 1 + a < sqrt(2)
+    let log2_approx = (x.numer.log2_accurate().sub(&x.denom.log2_accurate()).to_i64().unwrap() + (1 << 31)) >> 32;
     let mut x_iter = x.clone();
     let mut log2_approx_counter = log2_approx.abs();
 
@@ -65,14 +66,57 @@ pub fn ln_iter(x: &Ratio, iter: usize) -> Ratio {
     result.add(&ln2_iter(iter).mul_bi(&BigInt::from_i64(log2_approx)))
 }
 
+/// It returns `ln(1 + x)`. It gets more accurate as `iter` gets bigger. It panics when `x` is less than or equal to -1.\
+/// When `x` is close to 0, it converges much faster than `ln_iter(1 + x)`.
+pub fn ln_1p_iter(x: &Ratio, iter: usize) -> Ratio {
+    // the series below is only good for small `x`
+    if x.abs().geq(&Ratio::from_denom_and_numer_i32(2, 1)) {
+        return ln_iter(&x.add_i32(1), iter);
+    }
+
+    // ln(1 + x) = x - x^2/2 + x^3/3 - x^4/4...
+    let mut x_iter = x.clone();
+    let mut result = x.clone();
+
+    for k in 0..iter {
+        x_iter.mul_mut(x);
+        result.sub_mut(&x_iter.div_i32((2 * k + 2) as i32));
+        x_iter.mul_mut(x);
+        result.add_mut(&x_iter.div_i32((2 * k + 3) as i32));
+    }
+
+    result
+}
+
 /// It returns log(x) with base `base`. It gets more accurate as `iter` gets bigger. It panics when `x` or `base` is less than or equal 0.
 pub fn log_iter(base: &Ratio, x: &Ratio, iter: usize) -> Ratio {
     ln_iter(x, iter).div(&ln_iter(base, iter))
 }
 
+/// It returns `log_base(x)`, which is `ln(x) / ln(base)`. It gets more accurate as `iter` gets bigger.\
+/// `base` can be any positive ratio except 1: `log_base_iter(8, 1/2)` is -3.\
+/// It panics when `x <= 0`, `base <= 0` or `base == 1`.\
+/// It's the same as `log_iter`, except for the order of the arguments.
+pub fn log_base_iter(x: &Ratio, base: &Ratio, iter: usize) -> Ratio {
+    if base.is_neg() || base.is_zero() {
+        panic!("logarithm with a non-positive base is undefined: base = {base}");
+    }
+
+    if base.eq_i32(1) {
+        panic!("logarithm with base 1 is undefined");
+    }
+
+    ln_iter(x, iter).div(&ln_iter(base, iter))
+}
+
+/// It returns `log10(x)`. It gets more accurate as `iter` gets bigger. It panics when `x` is less than or equal to 0.
+pub fn log10_iter(x: &Ratio, iter: usize) -> Ratio {
+    log_base_iter(x, &Ratio::from_i32(10), iter)
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{Ratio, BigInt, ln_iter, exp_iter, log_iter};
+    use crate::{Ratio, BigInt, ln_iter, ln_1p_iter, exp_iter, log_iter, log_base_iter, log10_iter};
     use crate::utils::are_close;
 
     #[test]
@@ -89,6 +133,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ln_1p_test() {
+        for x in ["0.3", "-0.3", "0.001", "-0.0001", "0.49", "0.5", "-0.7", "2.5", "-0.999"] {
+            let x = Ratio::from_string(x).unwrap();
+
+            assert!(are_close(&ln_1p_iter(&x, 16), &ln_iter(&x.add_i32(1), 16), 1e-8));
+        }
+
+        // for a tiny `x`, only a few terms are needed
+        let tiny = Ratio::from_string("1e-20").unwrap();
+
+        for x in [tiny.clone(), tiny.neg()] {
+            assert!(are_close(&ln_1p_iter(&x, 1), &ln_1p_iter(&x, 8), 1e-15));
+            assert!(are_close(&ln_1p_iter(&x, 1), &ln_iter(&x.add_i32(1), 16), 1e-15));
+        }
+
+        assert_eq!(ln_1p_iter(&Ratio::zero(), 4), Ratio::zero());
+    }
+
     #[test]
     fn log_test() {
         assert_eq!(
@@ -132,4 +195,55 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn log10_log_base_test() {
+        assert!(are_close(&log10_iter(&Ratio::from_i32(100), 12), &Ratio::from_i32(2), 1e-8));
+        assert!(are_close(&log10_iter(&Ratio::from_i32(1000), 12), &Ratio::from_i32(3), 1e-8));
+        assert!(are_close(&log10_iter(&Ratio::from_string("0.01").unwrap(), 12), &Ratio::from_i32(-2), 1e-8));
+        assert!(are_close(&log_base_iter(&Ratio::from_i32(8), &Ratio::from_i32(2), 12), &Ratio::from_i32(3), 1e-8));
+        assert!(are_close(&log_base_iter(&Ratio::from_i32(2), &Ratio::from_i32(8), 12), &Ratio::from_denom_and_numer_i32(3, 1), 1e-8));
+        assert!(log10_iter(&Ratio::one(), 4).is_zero());
+
+        // log_3(1/9) = -2
+        assert!(are_close(&log_base_iter(&Ratio::from_denom_and_numer_i32(9, 1), &Ratio::from_i32(3), 12), &Ratio::from_i32(-2), 1e-8));
+
+        // log_(1/2)(8) = -3
+        assert!(are_close(&log_base_iter(&Ratio::from_i32(8), &Ratio::from_denom_and_numer_i32(2, 1), 12), &Ratio::from_i32(-3), 1e-8));
+
+        assert_eq!(
+            std::f64::consts::LOG10_2,
+            log10_iter(&2.into(), 11).to_ieee754_f64().unwrap(),
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn log_base_one_test() {
+        log_base_iter(&Ratio::from_i32(8), &Ratio::one(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn log_base_negative_test() {
+        log_base_iter(&Ratio::from_i32(8), &Ratio::from_i32(-2), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn log_base_zero_base_test() {
+        log_base_iter(&Ratio::from_i32(8), &Ratio::zero(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn log_base_negative_x_test() {
+        log_base_iter(&Ratio::from_i32(-8), &Ratio::from_i32(2), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn log10_zero_test() {
+        log10_iter(&Ratio::zero(), 4);
+    }
 }
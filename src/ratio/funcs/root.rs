@@ -8,6 +8,43 @@ pub fn sqrt_iter(x: &Ratio, iter: usize) -> Ratio {
     )
 }
 
+/// It returns `sqrt(x)` where `abs(result^2 - x) <= 10^-decimal_digits`.\
+/// It runs Newton's method until the result is precise enough. It panics when `x` is negative.
+pub fn sqrt_prec(x: &Ratio, decimal_digits: usize) -> Ratio {
+    if x.is_neg() {
+        panic!("Attempt to get a square root of a negative number: {x:?}");
+    }
+
+    if x.is_zero() {
+        return Ratio::zero();
+    }
+
+    let tolerance = Ratio::from_denom_and_numer(BigInt::from_i32(10).pow_u32(decimal_digits as u32), BigInt::one());
+    let mut approx = sqrt_iter(x, 0);
+
+    // Newton's method doubles the number of correct digits each step, but the denominator also gets squared,
+    // so it drops the digits that don't help: log2(10) < 10/3
+    let bits = (decimal_digits as u32 * 10 / 3 + 16).max(32);
+
+    loop {
+        let error = approx.mul(&approx).sub(x).abs();
+
+        if error.leq(&tolerance) {
+            return approx;
+        }
+
+        // a2 = (a1 + x / a1) / 2
+        approx = approx.add(&x.div(&approx)).div_i32(2);
+
+        // a larger `approx` needs more bits because the error of `approx^2` is about `2 * approx * error of approx`
+        let extra_bits = approx.numer.log2().sub(&approx.denom.log2()).to_i64().unwrap().max(0) as u32;
+        let denom = BigInt::from_ubi(UBigInt::pow2(bits + extra_bits), false);
+        let numer = approx.numer.mul(&denom).div(&approx.denom);
+
+        approx = Ratio::from_denom_and_numer(denom, numer);
+    }
+}
+
 /// It returns `cbrt(x)`. It gets more accurate as `iter` gets bigger.
 pub fn cbrt_iter(x: &Ratio, iter: usize) -> Ratio {
 
@@ -43,7 +80,7 @@ pub fn cbrt_iter(x: &Ratio, iter: usize) -> Ratio {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Ratio, sqrt_iter, cbrt_iter, pow_iter};
+    use crate::{Ratio, sqrt_iter, sqrt_prec, cbrt_iter, pow_iter};
     use crate::utils::are_close;
     use crate::consts::RUN_ALL_TESTS;
 
@@ -90,4 +127,40 @@ mod tests {
             sqrt_iter(&2.into(), 3).to_ieee754_f64().unwrap(),
         );
     }
+
+    #[test]
+    fn sqrt_prec_test() {
+        let two = Ratio::from_i32(2);
+        let sqrt2 = sqrt_prec(&two, 30);
+
+        assert!(sqrt2.mul(&sqrt2).sub(&two).abs().leq(&Ratio::from_string("1e-30").unwrap()));
+        assert_eq!(sqrt2.to_approx_string(24), "1.4142135623730950488016");
+
+        let samples = [
+            Ratio::one(),
+            Ratio::from_i32(4),
+            Ratio::from_denom_and_numer_i32(3, 1),
+            Ratio::from_string("0.000000123").unwrap(),
+            Ratio::from_string("123456789012345678901234567890").unwrap(),
+            Ratio::from_string("3.1415926535").unwrap(),
+        ];
+
+        for x in samples.iter() {
+            for digits in [0, 5, 30, 100] {
+                let result = sqrt_prec(x, digits);
+                let tolerance = Ratio::from_string(&format!("1e-{digits}")).unwrap();
+
+                assert!(result.mul(&result).sub(x).abs().leq(&tolerance), "{x}, {digits}");
+                assert!(!result.is_neg());
+            }
+        }
+
+        assert_eq!(sqrt_prec(&Ratio::zero(), 30), Ratio::zero());
+    }
+
+    #[test]
+    #[should_panic]
+    fn sqrt_prec_neg_test() {
+        let _ = sqrt_prec(&Ratio::from_i32(-2), 10);
+    }
 }
@@ -0,0 +1,100 @@
+use crate::{Ratio, BigInt, powers_of};
+
+/// A power series `c_0 + c_1 * x + c_2 * x^2 + ...` whose coefficients are generated by a closure.\
+/// `TaylorSeries::new(|k| ...)` takes `k` and returns `c_k`.
+pub struct TaylorSeries {
+    coeff: Box<dyn Fn(usize) -> Ratio>,
+}
+
+impl TaylorSeries {
+
+    pub fn new(coeff: impl Fn(usize) -> Ratio + 'static) -> Self {
+        TaylorSeries { coeff: Box::new(coeff) }
+    }
+
+    /// `e^x = sigma{k=0}{inf} x^k / k!`
+    pub fn exp() -> Self {
+        TaylorSeries::new(|k| Ratio::from_bi(BigInt::factorial(k as u32)).reci())
+    }
+
+    /// `sin(x) = sigma{k=0}{inf} (-1)^k * x^(2k+1) / (2k+1)!`
+    pub fn sin() -> Self {
+        TaylorSeries::new(|k| {
+            if k & 1 == 0 {
+                return Ratio::zero();
+            }
+
+            let mut result = Ratio::from_bi(BigInt::factorial(k as u32)).reci();
+
+            if k % 4 == 3 {
+                result.neg_mut();
+            }
+
+            result
+        })
+    }
+
+    /// It returns `c_k`.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn coeff(&self, k: usize) -> Ratio {
+        (self.coeff)(k)
+    }
+
+    /// It returns `c_0 + c_1 * x + ... + c_(terms - 1) * x^(terms - 1)`.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn evaluate(&self, x: &Ratio, terms: usize) -> Ratio {
+        let mut result = Ratio::zero();
+
+        for (k, power) in powers_of(x).take(terms).enumerate() {
+            let coeff = self.coeff(k);
+
+            if !coeff.is_zero() {
+                result.add_mut(&coeff.mul(&power));
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Ratio, TaylorSeries, exp_iter};
+    use crate::utils::are_close;
+
+    #[test]
+    fn taylor_series_test() {
+        let exp = TaylorSeries::exp();
+
+        // `exp_iter` doesn't reduce `x` in this range, so the sums are exactly the same
+        for x in ["0", "0.1", "0.5", "-0.7", "0.85"] {
+            let x = Ratio::from_string(x).unwrap();
+
+            for iter in [1, 4, 9] {
+                assert_eq!(exp.evaluate(&x, iter + 1), exp_iter(&x, iter));
+            }
+        }
+
+        assert!(exp.evaluate(&Ratio::from_i32(3), 0).is_zero());
+        assert_eq!(exp.coeff(4), Ratio::from_denom_and_numer_i32(24, 1));
+
+        let sin = TaylorSeries::sin();
+
+        for x in [0.25, -0.5, 1.0, 2.0] {
+            assert!(are_close(
+                &sin.evaluate(&Ratio::from_ieee754_f64(x).unwrap(), 24),
+                &Ratio::from_ieee754_f64(x.sin()).unwrap(),
+                1e-12,
+            ));
+        }
+
+        // 1 + x + x^2 + ... = 1 / (1 - x)
+        let geometric = TaylorSeries::new(|_| Ratio::one());
+        let x = Ratio::from_denom_and_numer_i32(3, 1);
+
+        assert_eq!(
+            geometric.evaluate(&x, 5),
+            Ratio::from_denom_and_numer_i32(81, 121),
+        );
+    }
+}
@@ -163,12 +163,66 @@ pub fn tan_iter(x: &Ratio, iter: usize) -> Ratio {
     sin_iter(x, iter).div(&cos_iter(x, iter))
 }
 
+/// If `t = tan(x)`, it returns `tan(2x) = 2t / (1 - t^2)`. The result is exact.\
+/// It panics when `t` is 1 or -1.
+pub fn tan_double(t: &Ratio) -> Ratio {
+    let denom = Ratio::one().sub(&t.mul(t));
+
+    if denom.is_zero() {
+        panic!("tan(2x) is undefined when tan(x) = {}", t.to_ratio_string());
+    }
+
+    t.mul_i32(2).div(&denom)
+}
+
+/// If `u = tan(x / 2)`, it returns `sin(x) = 2u / (1 + u^2)`. The result is exact.
+pub fn sin_from_tan_half(u: &Ratio) -> Ratio {
+    u.mul_i32(2).div(&u.mul(u).add_i32(1))
+}
+
+/// If `u = tan(x / 2)`, it returns `cos(x) = (1 - u^2) / (1 + u^2)`. The result is exact.
+pub fn cos_from_tan_half(u: &Ratio) -> Ratio {
+    let u_sqr = u.mul(u);
+
+    Ratio::one().sub(&u_sqr).div(&u_sqr.add_i32(1))
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{Ratio, sqrt_iter, sin_iter, cos_iter, pi_iter};
+    use crate::{Ratio, sqrt_iter, sin_iter, cos_iter, tan_iter, pi_iter};
+    use crate::{tan_double, sin_from_tan_half, cos_from_tan_half};
     use crate::utils::are_close;
     use crate::consts::RUN_ALL_TESTS;
 
+    #[test]
+    fn tan_double_test() {
+        for t in ["0.3", "-0.3", "0.5", "2", "-1.7", "0.01"] {
+            let t = Ratio::from_string(t).unwrap();
+            let (sin, cos) = (sin_from_tan_half(&t), cos_from_tan_half(&t));
+
+            assert_eq!(sin.mul(&sin).add(&cos.mul(&cos)), Ratio::one());
+            assert_eq!(sin.div(&cos), tan_double(&t));
+        }
+
+        // tan(2 * atan(t)) = tan_double(t)
+        for x in ["0.3", "-0.3", "0.1", "-0.35"] {
+            let x = Ratio::from_string(x).unwrap();
+            let t = tan_iter(&x, 8);
+
+            assert!(are_close(&tan_double(&t), &tan_iter(&x.mul_i32(2), 8), 1e-6));
+        }
+
+        for x in ["0.3", "-0.3", "0.7", "-0.5"] {
+            let x = Ratio::from_string(x).unwrap();
+            let u = tan_iter(&x.div_i32(2), 8);
+
+            assert!(are_close(&sin_from_tan_half(&u), &sin_iter(&x, 8), 1e-6));
+            assert!(are_close(&cos_from_tan_half(&u), &cos_iter(&x, 8), 1e-6));
+        }
+
+        assert_eq!(tan_double(&Ratio::zero()), Ratio::zero());
+    }
+
     #[test]
     fn sin_test() {
         assert!(sin_iter(&"314.159265358979323846264338".parse::<Ratio>().unwrap(), 11).lt(&"1e-21".parse::<Ratio>().unwrap()));
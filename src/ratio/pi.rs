@@ -1,4 +1,4 @@
-use crate::{BigInt, Ratio};
+use crate::{BigInt, Ratio, continued_product, pi_const};
 
 // https://en.wikipedia.org/wiki/Bailey%E2%80%93Borwein%E2%80%93Plouffe_formula
 
@@ -22,6 +22,43 @@ pub fn pi_iter(k: usize) -> Ratio {
     result
 }
 
+/// It returns an approximate value of PI using the first `n` terms of the Wallis product: `pi / 2 = (2/1 * 2/3) * (4/3 * 4/5) * (6/5 * 6/7) * ...`\
+/// It converges very slowly: `0 < pi - wallis_pi(n) < 1 / n`. Use `pi_iter` if you need an accurate value.
+pub fn wallis_pi(n: usize) -> Ratio {
+    let terms = (1..).map(|k: i64| {
+        let k2 = 4 * k * k;
+
+        Ratio::from_denom_and_numer(BigInt::from_i64(k2 - 1), BigInt::from_i64(k2))
+    });
+
+    // pi = 2 * (pi / 2)
+    continued_product(std::iter::once(Ratio::from_i32(2)).chain(terms), n + 1)
+}
+
+impl Ratio {
+    /// If `self` is very close to `k * pi` (within `k * pi ± 1e-12 * pi`), it returns `Some(k)`.
+    /// The denominator of `k` is at most `max_k_denom`. If there are multiple candidates, the one with the smallest denominator is returned.\
+    /// It tries every denominator from 1 to `max_k_denom`, so the cost is linear in `max_k_denom`.
+    pub fn detect_pi_multiple(&self, max_k_denom: u32) -> Option<Ratio> {
+        let k = self.div(&pi_const());
+
+        // 1e-12
+        let tolerance = Ratio::from_denom_and_numer(BigInt::from_i64(10).pow_u32(12), BigInt::one());
+
+        for denom in 1..=max_k_denom {
+            let k_denom = k.mul_bi(&BigInt::from_i64(denom as i64));
+            let numer = k_denom.round_bi();
+
+            // |k - numer / denom| < tolerance
+            if k_denom.sub_bi(&numer).abs().lt(&tolerance.mul_bi(&BigInt::from_i64(denom as i64))) {
+                return Some(Ratio::from_denom_and_numer(BigInt::from_i64(denom as i64), numer));
+            }
+        }
+
+        None
+    }
+}
+
 const PI_TABLE: [(i128, i128); 255] = [
     //(0x19201, 0x7ff8),
     (0x1148f, 0x18d314),
@@ -283,7 +320,9 @@ const PI_TABLE: [(i128, i128); 255] = [
 
 #[cfg(test)]
 mod tests {
-    use super::pi_iter;
+    use super::{pi_iter, wallis_pi};
+    use crate::Ratio;
+    use std::cmp::Ordering;
 
     #[test]
     fn pi_test() {
@@ -313,4 +352,39 @@ mod tests {
             pi_iter(5).to_ieee754_f64().unwrap(),
         );
     }
+
+    #[test]
+    fn detect_pi_multiple_test() {
+        let pi = pi_iter(8);
+
+        assert_eq!(pi.div_i32(2).detect_pi_multiple(8), Some(Ratio::from_denom_and_numer_i32(2, 1)));
+        assert_eq!(pi.mul_i32(-3).div_i32(4).detect_pi_multiple(8), Some(Ratio::from_denom_and_numer_i32(4, -3)));
+        assert_eq!(pi.mul_i32(5).detect_pi_multiple(1), Some(Ratio::from_i32(5)));
+        assert_eq!(Ratio::zero().detect_pi_multiple(1), Some(Ratio::zero()));
+
+        // 5 / 7 is not allowed when `max_k_denom` is 6
+        assert_eq!(pi.mul_i32(5).div_i32(7).detect_pi_multiple(6), None);
+
+        // 355 / 113 is not close enough
+        assert_eq!(Ratio::from_denom_and_numer_i32(113, 355).detect_pi_multiple(16), None);
+        assert_eq!(Ratio::one().detect_pi_multiple(16), None);
+    }
+
+    #[test]
+    fn wallis_pi_test() {
+        let pi = pi_iter(8);
+
+        assert_eq!(wallis_pi(0), Ratio::from_i32(2));
+        assert_eq!(wallis_pi(1), Ratio::from_denom_and_numer_i32(3, 8));
+        assert_eq!(wallis_pi(2), Ratio::from_denom_and_numer_i32(45, 128));
+
+        for n in [1, 10, 100, 1000, 10000] {
+            let approx = wallis_pi(n);
+            let tolerance = Ratio::from_denom_and_numer_i32(n as i32, 1);
+
+            // `comp` doesn't have to calculate a gcd of the huge numerator and denominator
+            assert_eq!(approx.comp(&pi), Ordering::Less);
+            assert_eq!(approx.comp(&pi.sub(&tolerance)), Ordering::Greater);
+        }
+    }
 }
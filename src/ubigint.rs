@@ -1,7 +1,9 @@
 mod arith;
+mod bytes;
 mod comp;
 pub mod convert;
 pub mod funcs;
+mod sieve;
 
 // data is always in the most reduced form
 // [0] is the least-significant number
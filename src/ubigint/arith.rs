@@ -1,4 +1,4 @@
-use crate::UBigInt;
+use crate::{UBigInt, impl_ops};
 
 mod add;
 mod div;
@@ -7,6 +7,16 @@ mod pow;
 mod rem;
 mod sub;
 
+// `a - b` panics when `b > a`, and `a / b` and `a % b` panic when `b` is 0, like the methods do.
+impl_ops!(
+    UBigInt,
+    Add, add, AddAssign, add_assign, add, add_mut;
+    Sub, sub, SubAssign, sub_assign, sub, sub_mut;
+    Mul, mul, MulAssign, mul_assign, mul, mul_mut;
+    Div, div, DivAssign, div_assign, div, div_mut;
+    Rem, rem, RemAssign, rem_assign, rem, rem_mut
+);
+
 impl std::iter::Sum for UBigInt {
     fn sum<I: Iterator<Item = UBigInt>>(mut iter: I) -> Self {
         let mut result = UBigInt::zero();
@@ -42,4 +52,58 @@ mod tests {
         //assert_eq!(v1.iter().sum::<UBigInt>(), UBigInt::from_u32(210));
         assert_eq!(v1.into_iter().sum::<UBigInt>(), UBigInt::from_u32(210));
     }
+
+    #[test]
+    fn ops_test() {
+        let numbers = [
+            UBigInt::one(),
+            UBigInt::from_u32(7),
+            UBigInt::from_u32(u32::MAX),
+            UBigInt::from_u128(u128::MAX / 3),
+            UBigInt::from_u32(3).pow_u32(150),
+        ];
+
+        for a in numbers.iter() {
+            for b in numbers.iter() {
+                assert_eq!(a + b, a.add(b));
+                assert_eq!(a * b, a.mul(b));
+                assert_eq!(a / b, a.div(b));
+                assert_eq!(a % b, a.rem(b));
+
+                assert_eq!(a.clone() + b, a.add(b));
+                assert_eq!(a + b.clone(), a.add(b));
+                assert_eq!(a.clone() * b.clone(), a.mul(b));
+
+                if a >= b {
+                    assert_eq!(a - b, a.sub(b));
+                    assert_eq!(a.clone() - b.clone(), a.sub(b));
+                }
+
+                let mut c = a.clone();
+                c += b;
+                c *= b.clone();
+                c -= a;
+                c /= b;
+                c %= a.clone();
+                assert_eq!(c, a.add(b).mul(b).sub(a).div(b).rem(a));
+            }
+        }
+
+        // (a + b)^2 = a^2 + 2ab + b^2
+        let a = UBigInt::from_u32(12345).pow_u32(20);
+        let b = UBigInt::from_u32(67890).pow_u32(17);
+        assert_eq!((&a + &b) * (&a + &b), &a * &a + UBigInt::from_u32(2) * &a * &b + &b * &b);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ops_sub_underflow_test() {
+        let _ = UBigInt::from_u32(3) - UBigInt::from_u32(4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ops_div_zero_test() {
+        let _ = UBigInt::from_u32(3) / UBigInt::zero();
+    }
 }
@@ -114,6 +114,18 @@ impl UBigInt {
         }
     }
 
+    /// (self / other, self % other)\
+    /// It's cheaper than calling `div` and `rem` separately, because `rem` calls `div` internally.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn div_rem(&self, other: &UBigInt) -> (Self, Self) {
+        let quotient = self.div(other);
+        let remainder = self.sub(&quotient.mul(other));
+
+        #[cfg(test)] assert!(remainder.lt(other));
+
+        (quotient, remainder)
+    }
+
     pub fn div_mut(&mut self, other: &UBigInt) {
         let result = self.div(other);
         *self = result;
@@ -135,7 +147,18 @@ impl UBigInt {
         result
     }
 
-    pub fn div_u32_mut(&mut self, other: u32) {
+    /// (self / other, self % other)\
+    /// It gets both in a single pass.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn div_rem_u32(&self, other: u32) -> (Self, u32) {
+        let mut quotient = self.clone();
+        let remainder = quotient.div_rem_u32_mut(other);
+
+        (quotient, remainder)
+    }
+
+    // self /= other, and returns self % other
+    pub(crate) fn div_rem_u32_mut(&mut self, other: u32) -> u32 {
         let mut carry = 0;
         let other = other as u64;
 
@@ -148,6 +171,12 @@ impl UBigInt {
         remove_suffix_0(&mut self.0);
 
         #[cfg(test)] assert!(self.is_valid());
+
+        (carry >> 32) as u32
+    }
+
+    pub fn div_u32_mut(&mut self, other: u32) {
+        self.div_rem_u32_mut(other);
     }
 }
 
@@ -165,6 +194,7 @@ fn div_approx(divend: Vec<u32>, divisor: u64) -> UBigInt {
 #[cfg(test)]
 mod tests {
     use crate::UBigInt;
+    use crate::utils::rand_u32;
     use crate::consts::RUN_ALL_TESTS;
 
     #[test]
@@ -224,4 +254,43 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn div_rem_test() {
+        let mut seed = 37u32;
+        let mut rand = || rand_u32(&mut seed);
+
+        for _ in 0..256 {
+            let self_len = rand() as usize % 12 + 1;
+            let other_len = rand() as usize % 12 + 1;
+            let mut a = (0..self_len).map(|_| rand()).collect::<Vec<u32>>();
+            let mut b = (0..other_len).map(|_| rand()).collect::<Vec<u32>>();
+
+            // the most significant limb must not be 0
+            a.push(rand() | 1);
+            b.push(rand() | 1);
+
+            let a = UBigInt::from_raw(a);
+            let b = UBigInt::from_raw(b);
+
+            // including `b > a`
+            for (a, b) in [(&a, &b), (&b, &a)] {
+                assert_eq!(a.div_rem(b), (a.div(b), a.rem(b)));
+            }
+
+            let small = rand() >> (rand() % 32);
+
+            if small != 0 {
+                let (q, r) = a.div_rem_u32(small);
+                assert_eq!(q, a.div_u32(small));
+                assert_eq!(UBigInt::from_u32(r), a.rem_u32(small));
+            }
+        }
+
+        assert_eq!(UBigInt::from_u32(7).div_rem(&UBigInt::from_u32(9)), (UBigInt::zero(), UBigInt::from_u32(7)));
+        assert_eq!(UBigInt::from_u32(7).div_rem_u32(9), (UBigInt::zero(), 7));
+        assert_eq!(UBigInt::zero().div_rem_u32(9), (UBigInt::zero(), 0));
+        assert_eq!(UBigInt::from_u64(u64::MAX).div_rem_u32(u32::MAX), (UBigInt::from_u64(u32::MAX as u64 + 2), 0));
+        assert_eq!(UBigInt::from_u64(u64::MAX).div_rem_u32(10), (UBigInt::from_u64(u64::MAX / 10), 5));
+    }
 }
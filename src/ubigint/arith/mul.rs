@@ -1,7 +1,10 @@
 use crate::UBigInt;
 use crate::utils::{v64_to_v32, remove_suffix_0};
+use crate::consts::DEFAULT_MUL_THRESHOLD;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
-const KARATSUBA_THRES: usize = 64;
+// if both operands are longer than this (in limbs), it uses the Karatsuba algorithm
+static KARATSUBA_THRES: AtomicUsize = AtomicUsize::new(DEFAULT_MUL_THRESHOLD);
 
 #[cfg(test)]
 const KARATSUBA_TEST: bool = crate::consts::RUN_ALL_TESTS & true;
@@ -11,46 +14,88 @@ static mut KARATSUBA_ENABLE: bool = true;
 
 impl UBigInt {
 
+    /// If both operands of `mul` are longer than this (number of `u32` limbs), it uses the Karatsuba algorithm. Otherwise, it uses the schoolbook algorithm.
+    pub fn mul_threshold() -> usize {
+        KARATSUBA_THRES.load(Ordering::Relaxed)
+    }
+
+    /// It sets `UBigInt::mul_threshold()` for the entire program. The default value is `DEFAULT_MUL_THRESHOLD`.\
+    /// `usize::MAX` disables the Karatsuba algorithm. 0 is treated as 1.
+    pub fn set_mul_threshold(limbs: usize) {
+        KARATSUBA_THRES.store(limbs.max(1), Ordering::Relaxed);
+    }
+
     #[must_use = "method returns a new number and does not mutate the original value"]
     pub fn mul(&self, other: &UBigInt) -> Self {
+        self.mul_with_threshold(other, UBigInt::mul_threshold())
+    }
+
+    // `mul` with a given threshold, instead of `UBigInt::mul_threshold()`
+    // the tests use it instead of `set_mul_threshold`, which changes the threshold of the other tests running in parallel
+    pub(crate) fn mul_with_threshold(&self, other: &UBigInt, thres: usize) -> Self {
 
         #[cfg(test)]
         let go_kara = unsafe { KARATSUBA_ENABLE };
         #[cfg(not(test))]
         let go_kara = true;
 
+        if self.len() > thres && other.len() > thres && go_kara {
+            self.mul_karatsuba_with_threshold(other, thres)
+        }
+
+        else {
+            self.mul_schoolbook(other)
+        }
+    }
+
+    /// It always uses the Karatsuba algorithm for the first step, regardless of `UBigInt::mul_threshold()`. The smaller steps are done by `mul`.\
+    /// It's for benchmarks: `mul` chooses the algorithm for you.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn mul_karatsuba(&self, other: &UBigInt) -> Self {
+        self.mul_karatsuba_with_threshold(other, UBigInt::mul_threshold())
+    }
+
+    // the smaller steps are done by `mul_with_threshold(.., thres)`
+    fn mul_karatsuba_with_threshold(&self, other: &UBigInt, thres: usize) -> Self {
+        // it cannot split a number with 1 limb
+        if self.len() < 2 || other.len() < 2 {
+            return self.mul_schoolbook(other);
+        }
+
         // https://en.wikipedia.org/wiki/Karatsuba_algorithm
-        if self.len() > KARATSUBA_THRES && other.len() > KARATSUBA_THRES && go_kara {
-
-            // self: a, other: b
-            // naive: O(a * b)
-            // karatsuba: O(a + b + (a - m) * (b - m) + m * m + a1 * b1)
-            let m = (self.len() / 2).min(other.len() / 2);
-            let x1 = self.shift_right(m);   // O(a - m)
-            let x0 = self.slice_right(m);   // O(m)
-            let y1 = other.shift_right(m);  // O(b - m)
-            let y0 = other.slice_right(m);  // O(m)
-            let z2 = x1.mul(&y1);  // O((a - m) * (b - m))
-            let z0 = x0.mul(&y0);  // O(m * m)
-
-            // a1 = max(m, a - m), b1 = max(m, b - m)
-            let z1 = x1.add(&x0).mul(&y1.add(&y0)).sub(&z2).sub(&z0);  // O(a1 * b1 + 3 * (a1 + b1))
-
-            let result = z2.shift_left(2 * m).add(&z1.shift_left(m)).add(&z0);
-
-            #[cfg(test)] unsafe {
-                if KARATSUBA_TEST {
-                    KARATSUBA_ENABLE = false;
-                    let result2 = self.mul(&other);
-                    KARATSUBA_ENABLE = true;
-
-                    assert_eq!(result, result2);
-                }
+        // self: a, other: b
+        // naive: O(a * b)
+        // karatsuba: O(a + b + (a - m) * (b - m) + m * m + a1 * b1)
+        let m = (self.len() / 2).min(other.len() / 2);
+        let x1 = self.shift_right(m);   // O(a - m)
+        let x0 = self.slice_right(m);   // O(m)
+        let y1 = other.shift_right(m);  // O(b - m)
+        let y0 = other.slice_right(m);  // O(m)
+        let z2 = x1.mul_with_threshold(&y1, thres);  // O((a - m) * (b - m))
+        let z0 = x0.mul_with_threshold(&y0, thres);  // O(m * m)
+
+        // a1 = max(m, a - m), b1 = max(m, b - m)
+        let z1 = x1.add(&x0).mul_with_threshold(&y1.add(&y0), thres).sub(&z2).sub(&z0);  // O(a1 * b1 + 3 * (a1 + b1))
+
+        let result = z2.shift_left(2 * m).add(&z1.shift_left(m)).add(&z0);
+
+        #[cfg(test)] unsafe {
+            if KARATSUBA_TEST {
+                KARATSUBA_ENABLE = false;
+                let result2 = self.mul(&other);
+                KARATSUBA_ENABLE = true;
+
+                assert_eq!(result, result2);
             }
-
-            return result;
         }
 
+        result
+    }
+
+    /// It always uses the schoolbook algorithm, which is O(a * b).\
+    /// It's for benchmarks: `mul` chooses the algorithm for you.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn mul_schoolbook(&self, other: &UBigInt) -> Self {
         let mut result = vec![0; self.len() + other.len()];
 
         for i in 0..self.len() {
@@ -144,7 +189,71 @@ impl UBigInt {
 
 #[cfg(test)]
 mod tests {
-    use crate::UBigInt;
+    use crate::{DEFAULT_MUL_THRESHOLD, UBigInt};
+    use crate::utils::rand_u32;
+
+    #[test]
+    fn mul_threshold_test() {
+        let mut seed = 31u32;
+        let mut samples = vec![];
+
+        for len in [1, 2, 3, 17, 65, 130, 300] {
+            samples.push(UBigInt::from_raw((0..len).map(|_| rand_u32(&mut seed) | 1).collect()));
+        }
+
+        let product = |samples: &[UBigInt], thres: usize| {
+            let mut result = vec![];
+
+            for a in samples.iter() {
+                for b in samples.iter() {
+                    result.push(a.mul_with_threshold(b, thres));
+                }
+            }
+
+            result
+        };
+
+        assert_eq!(UBigInt::mul_threshold(), DEFAULT_MUL_THRESHOLD);
+        let default = samples.iter().flat_map(|a| samples.iter().map(|b| a.mul(b))).collect::<Vec<_>>();
+
+        // always schoolbook
+        let schoolbook = product(&samples, usize::MAX);
+
+        // Karatsuba all the way down
+        let karatsuba = product(&samples, 1);
+
+        assert_eq!(default, product(&samples, DEFAULT_MUL_THRESHOLD));
+        assert_eq!(default, schoolbook);
+        assert_eq!(default, karatsuba);
+
+        // (2^(32n) - 1)^2 = 2^(64n) - 2^(32n + 1) + 1
+        let n = UBigInt::pow2(32 * 200).sub_u32(1);
+        let answer = UBigInt::pow2(64 * 200).sub(&UBigInt::pow2(32 * 200 + 1)).add_u32(1);
+
+        for thres in [0, 1, 7, 64, 199, usize::MAX] {
+            assert_eq!(n.mul_with_threshold(&n, thres), answer);
+        }
+    }
+
+    #[test]
+    fn mul_karatsuba_test() {
+        let mut seed = 43u32;
+        let mut samples = vec![UBigInt::zero(), UBigInt::one(), UBigInt::from_u32(u32::MAX)];
+
+        for len in [1, 2, 3, 5, 8, 33, 64, 65, 100, 129, 250] {
+            samples.push(UBigInt::from_raw((0..len).map(|_| rand_u32(&mut seed) | 1).collect()));
+            samples.push(UBigInt::from_raw(vec![u32::MAX; len]));
+        }
+
+        for a in samples.iter() {
+            for b in samples.iter() {
+                let result = a.mul(b);
+
+                assert_eq!(a.mul_karatsuba(b), result);
+                assert_eq!(a.mul_schoolbook(b), result);
+            }
+        }
+    }
 
     #[test]
     fn mul_pow2_test() {
@@ -1,5 +1,8 @@
 use crate::UBigInt;
 
+// `rem_multi` uses a remainder tree if there are at least this many moduli
+const REM_TREE_THRES: usize = 8;
+
 impl UBigInt {
 
     // self - self / other * other
@@ -64,6 +67,43 @@ impl UBigInt {
         }
     }
 
+    /// It returns `self % m` for each `m` in `moduli`. It panics if any of `moduli` is 0.\
+    /// If there are many moduli, it uses a remainder tree: it reduces `self` by the product of all the moduli first, then by the products of the halves, and so on.
+    /// That's much faster than calling `rem` for each modulus when `self` is much bigger than the moduli.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn rem_multi(&self, moduli: &[UBigInt]) -> Vec<Self> {
+        if moduli.iter().any(|m| m.is_zero()) {
+            panic!("Attempt to divide by zero: UBigInt::rem_multi");
+        }
+
+        if moduli.len() < REM_TREE_THRES {
+            return moduli.iter().map(|m| self.rem(m)).collect();
+        }
+
+        // tree[0] is `moduli`, and tree[i + 1][j] = tree[i][2j] * tree[i][2j + 1]
+        let mut tree = vec![moduli.to_vec()];
+
+        while tree[tree.len() - 1].len() > 1 {
+            let products = tree[tree.len() - 1].chunks(2).map(
+                |pair| if pair.len() == 2 { pair[0].mul(&pair[1]) } else { pair[0].clone() }
+            ).collect();
+
+            tree.push(products);
+        }
+
+        let mut remainders = vec![self.rem(&tree[tree.len() - 1][0])];
+
+        for level in tree.iter().rev().skip(1) {
+            remainders = level.iter().enumerate().map(
+                |(i, m)| remainders[i / 2].rem(m)
+            ).collect();
+        }
+
+        #[cfg(test)] assert_eq!(remainders, moduli.iter().map(|m| self.rem(m)).collect::<Vec<_>>());
+
+        remainders
+    }
+
     /// `other` must be a power of 2
     #[must_use = "method returns a new number and does not mutate the original value"]
     pub fn rem_pow2(&self, other: u32) -> Self {
@@ -90,3 +130,41 @@ fn is_pow2(mut n: u32) -> bool {
 
     n == 1 || n == 2 || n == 4
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::UBigInt;
+    use crate::utils::rand_u32;
+
+    #[test]
+    fn rem_multi_test() {
+        let n = UBigInt::factorial(300).add_u32(12345);
+        let primes = UBigInt::primes_below(2000);
+
+        assert_eq!(n.rem_multi(&primes), primes.iter().map(|p| n.rem(p)).collect::<Vec<_>>());
+
+        // the direct path
+        assert_eq!(n.rem_multi(&primes[..3]), primes[..3].iter().map(|p| n.rem(p)).collect::<Vec<_>>());
+        assert_eq!(n.rem_multi(&[]), vec![]);
+
+        let mut seed = 41u32;
+        let moduli = (0..37).map(|i| UBigInt::from_raw(vec![rand_u32(&mut seed), i + 1])).collect::<Vec<_>>();
+
+        for n in [UBigInt::zero(), UBigInt::from_u32(7), UBigInt::pow2(1000).sub_u32(1), moduli[3].clone()] {
+            assert_eq!(n.rem_multi(&moduli), moduli.iter().map(|m| n.rem(m)).collect::<Vec<_>>());
+        }
+
+        // duplicated moduli and 1
+        let moduli = [3, 3, 1, 5, 7, 3, 1, 11, 13].map(UBigInt::from_u32);
+        assert_eq!(
+            UBigInt::from_u32(1000).rem_multi(&moduli),
+            [1, 1, 0, 0, 6, 1, 0, 10, 12].map(UBigInt::from_u32).to_vec(),
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn rem_multi_panic_test() {
+        let _ = UBigInt::from_u32(7).rem_multi(&[0, 1, 2, 3, 4, 5, 6, 7, 8].map(UBigInt::from_u32));
+    }
+}
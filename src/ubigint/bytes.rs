@@ -0,0 +1,90 @@
+use crate::UBigInt;
+use crate::utils::remove_suffix_0;
+
+impl UBigInt {
+
+    /// The most significant byte comes first. It doesn't have leading zeros, except 0, which is `[0]`.
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        let mut result = self.to_bytes_le();
+        result.reverse();
+
+        result
+    }
+
+    /// The least significant byte comes first. It doesn't have trailing zeros, except 0, which is `[0]`.
+    pub fn to_bytes_le(&self) -> Vec<u8> {
+        let mut result = self.0.iter().flat_map(|limb| limb.to_le_bytes()).collect::<Vec<u8>>();
+
+        while result.len() > 1 && result[result.len() - 1] == 0 {
+            result.pop().unwrap();
+        }
+
+        result
+    }
+
+    /// The most significant byte comes first. An empty slice is 0.
+    pub fn from_bytes_be(bytes: &[u8]) -> Self {
+        let mut bytes = bytes.to_vec();
+        bytes.reverse();
+
+        UBigInt::from_bytes_le(&bytes)
+    }
+
+    /// The least significant byte comes first. An empty slice is 0.
+    pub fn from_bytes_le(bytes: &[u8]) -> Self {
+        let mut result = bytes.chunks(4).map(
+            |chunk| chunk.iter().rev().fold(0, |acc, byte| (acc << 8) | *byte as u32)
+        ).collect::<Vec<u32>>();
+
+        if result.is_empty() {
+            return UBigInt::zero();
+        }
+
+        remove_suffix_0(&mut result);
+        let result = UBigInt::from_raw(result);
+
+        #[cfg(test)] assert!(result.is_valid());
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UBigInt;
+    use crate::utils::rand_u32;
+
+    #[test]
+    fn bytes_test() {
+        assert_eq!(UBigInt::from_bytes_be(&[0x01, 0x00, 0x00, 0x00, 0x00]), UBigInt::from_u64(0x01_0000_0000));
+        assert_eq!(UBigInt::from_bytes_le(&[0x00, 0x00, 0x00, 0x00, 0x01]), UBigInt::from_u64(0x01_0000_0000));
+        assert_eq!(UBigInt::from_bytes_be(&[]), UBigInt::zero());
+        assert_eq!(UBigInt::from_bytes_le(&[]), UBigInt::zero());
+        assert_eq!(UBigInt::from_bytes_be(&[0, 0, 0, 0, 0, 0]), UBigInt::zero());
+        assert_eq!(UBigInt::from_bytes_be(&[0, 0, 0, 0, 0, 0x12, 0x34]), UBigInt::from_u32(0x1234));
+
+        assert_eq!(UBigInt::from_u64(0x01_0000_0000).to_bytes_be(), vec![0x01, 0x00, 0x00, 0x00, 0x00]);
+        assert_eq!(UBigInt::from_u32(0x12_3456).to_bytes_be(), vec![0x12, 0x34, 0x56]);
+        assert_eq!(UBigInt::from_u32(0x12_3456).to_bytes_le(), vec![0x56, 0x34, 0x12]);
+        assert_eq!(UBigInt::zero().to_bytes_be(), vec![0]);
+        assert_eq!(UBigInt::zero().to_bytes_le(), vec![0]);
+
+        let mut seed = 29u32;
+
+        for len in 1..40 {
+            let bytes = (0..len).map(|_| (rand_u32(&mut seed) >> 16) as u8).collect::<Vec<u8>>();
+            let n = UBigInt::from_bytes_be(&bytes);
+
+            assert_eq!(UBigInt::from_bytes_be(&n.to_bytes_be()), n);
+            assert_eq!(UBigInt::from_bytes_le(&n.to_bytes_le()), n);
+            assert_eq!(UBigInt::from_bytes_le(&bytes), UBigInt::from_bytes_be(&bytes.iter().rev().copied().collect::<Vec<u8>>()));
+
+            // no leading zeros
+            let be = n.to_bytes_be();
+            assert!(be[0] != 0 || be.len() == 1);
+
+            let skip = bytes.iter().take_while(|b| **b == 0).count();
+            assert_eq!(be, if skip == bytes.len() { vec![0] } else { bytes[skip..].to_vec() });
+        }
+    }
+}
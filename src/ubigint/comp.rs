@@ -177,6 +177,7 @@ impl Ord for UBigInt {
 mod tests {
     use crate::UBigInt;
     use std::cmp::Ordering;
+    use std::collections::BTreeSet;
 
     #[test]
     fn ubi_cmp_test() {
@@ -226,4 +227,33 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn ubi_ord_test() {
+        let mut set = BTreeSet::new();
+
+        for n in [5, 3, 100, 0, 3, u32::MAX, 5] {
+            set.insert(UBigInt::from_u32(n));
+        }
+
+        set.insert(UBigInt::exp2(100));
+        set.insert(UBigInt::exp2(64));
+        set.insert(UBigInt::from_u64(1 << 32).sub_u32(1));
+
+        assert_eq!(
+            set.into_iter().collect::<Vec<_>>(),
+            vec![
+                UBigInt::zero(),
+                UBigInt::from_u32(3),
+                UBigInt::from_u32(5),
+                UBigInt::from_u32(100),
+                UBigInt::from_u32(u32::MAX),
+                UBigInt::exp2(64),
+                UBigInt::exp2(100),
+            ],
+        );
+
+        assert!(UBigInt::exp2(64) > UBigInt::from_u64(u64::MAX));
+        assert!(UBigInt::from_u32(7) <= UBigInt::from_u32(7));
+    }
 }
@@ -1,10 +1,18 @@
-use crate::UBigInt;
+use crate::{Ratio, UBigInt};
 use crate::err::ConversionError;
+use crate::utils::remove_suffix_0;
 use std::fmt;
 use std::str::FromStr;
 
 mod from;
 mod into;
+mod roman;
+
+#[cfg(feature = "num-bigint")]
+mod num;
+
+#[cfg(feature = "serde")]
+mod serde;
 
 impl UBigInt {
 
@@ -76,6 +84,32 @@ impl UBigInt {
         }
     }
 
+    /// `from_bits([true, false, false], true)` is 4, and `from_bits([true, false, false], false)` is 1.\
+    /// An empty iterator gives 0.
+    pub fn from_bits<I: IntoIterator<Item = bool>>(bits: I, msb_first: bool) -> Self {
+        let mut bits = bits.into_iter().collect::<Vec<bool>>();
+
+        if msb_first {
+            bits.reverse();
+        }
+
+        let mut result = vec![0; bits.len() / 32 + 1];
+
+        for (index, bit) in bits.into_iter().enumerate() {
+            if bit {
+                result[index / 32] |= 1 << (index % 32);
+            }
+        }
+
+        remove_suffix_0(&mut result);
+
+        let result = UBigInt::from_raw(result);
+
+        #[cfg(test)] assert!(result.is_valid());
+
+        result
+    }
+
     /// `('0' '_'*) | ([1-9] ([0-9] | '_')*)`\
     /// `('0x' | '0X') ([0-9a-fA-F] | '_')+`\
     /// `('0b' | '0B') ('0' | '1' | '_')+`\
@@ -211,8 +245,7 @@ impl UBigInt {
         let mut buffer = Vec::with_capacity(self.len());
 
         while n.len() > 1 {
-            buffer.push(format!("{:09}", n.rem_u32(1_000_000_000).0[0]));
-            n.div_u32_mut(1_000_000_000);
+            buffer.push(format!("{:09}", n.div_rem_u32_mut(1_000_000_000)));
         }
 
         buffer.push(n.0[0].to_string());
@@ -280,6 +313,116 @@ impl UBigInt {
 
         buffer.concat()
     }
+
+    /// It uses lowercase letters for digits larger than 9. It doesn't add any prefix.\
+    /// It panics if `radix` is not in range 2..=36.
+    pub fn to_string_radix(&self, radix: u32) -> String {
+        if !(2..=36).contains(&radix) {
+            panic!("radix must be in range 2..=36, got {radix}");
+        }
+
+        // the largest power of `radix` that fits in u32
+        let mut chunk = radix;
+        let mut chunk_len = 1;
+
+        while let Some(n) = chunk.checked_mul(radix) {
+            chunk = n;
+            chunk_len += 1;
+        }
+
+        let to_digits = |mut n: u32, buffer: &mut Vec<char>| {
+            while n > 0 {
+                buffer.push(std::char::from_digit(n % radix, radix).unwrap());
+                n /= radix;
+            }
+        };
+
+        let mut n = self.clone();
+        let mut buffer = Vec::with_capacity(self.len() * 32);
+
+        while n.len() > 1 {
+            let start = buffer.len();
+            to_digits(n.rem_u32(chunk).0[0], &mut buffer);
+
+            // leading zeros of the chunk
+            while buffer.len() < start + chunk_len {
+                buffer.push('0');
+            }
+
+            n.div_u32_mut(chunk);
+        }
+
+        to_digits(n.0[0], &mut buffer);
+
+        if buffer.is_empty() {
+            buffer.push('0');
+        }
+
+        buffer.iter().rev().collect()
+    }
+
+    /// It accepts the strings that `Ratio::from_string` accepts, as long as the value is a non-negative integer.\
+    /// "1.5e3" -> 1500, "1.5e1" -> 15, "1500" -> 1500, "1.55e1" -> Err
+    pub fn from_scientific(s: &str) -> Result<Self, ConversionError> {
+        let n = Ratio::from_string(s)?;
+
+        if !n.is_integer() || n.is_neg() {
+            return Err(ConversionError::NotInRange { permitted: "non-negative integer".to_string(), error: s.to_string() });
+        }
+
+        n.get_numer().to_ubi()
+    }
+
+    /// Inverse of `to_string_radix`. It's case insensitive, and it ignores `_` like `from_string`. It doesn't accept any prefix.\
+    /// It panics if `radix` is not in range 2..=36.
+    pub fn from_string_radix(s: &str, radix: u32) -> Result<Self, ConversionError> {
+        if !(2..=36).contains(&radix) {
+            panic!("radix must be in range 2..=36, got {radix}");
+        }
+
+        let mut result = UBigInt::zero();
+        let mut int_buffer = 0;
+        let mut int_buffer_coeff = 1;
+        let mut has_digit = false;
+
+        for c in s.chars() {
+            if c == '_' && has_digit {
+                continue;
+            }
+
+            match c.to_digit(radix) {
+                Some(n) => {
+                    int_buffer = int_buffer * radix + n;
+                    int_buffer_coeff *= radix;
+                    has_digit = true;
+
+                    if int_buffer_coeff > u32::MAX / radix {
+                        result.mul_u32_mut(int_buffer_coeff);
+                        result.add_u32_mut(int_buffer);
+
+                        int_buffer = 0;
+                        int_buffer_coeff = 1;
+                    }
+                },
+                None => {
+                    return Err(ConversionError::InvalidChar(c));
+                },
+            }
+        }
+
+        if !has_digit {
+            return Err(ConversionError::NoData);
+        }
+
+        if int_buffer_coeff > 1 {
+            result.mul_u32_mut(int_buffer_coeff);
+            result.add_u32_mut(int_buffer);
+        }
+
+        #[cfg(test)] assert!(result.is_valid());
+
+        Ok(result)
+    }
 }
 
 #[derive(PartialEq)]
@@ -361,7 +504,7 @@ pub fn _to_scientific_notation(mut n: u64, digits_max_len: usize) -> (String, us
 
 #[cfg(test)]
 mod tests {
-    use crate::UBigInt;
+    use crate::{ConversionError, UBigInt};
     use crate::consts::RUN_ALL_TESTS;
 
     #[test]
@@ -425,6 +568,85 @@ mod tests {
         }
     }
 
+    #[test]
+    fn from_bits_test() {
+        assert_eq!(UBigInt::from_bits([true, false, true], true), UBigInt::from_u32(5));
+        assert_eq!(UBigInt::from_bits([true, true, false], true), UBigInt::from_u32(6));
+        assert_eq!(UBigInt::from_bits([true, true, false], false), UBigInt::from_u32(3));
+        assert_eq!(UBigInt::from_bits([false, false, false], true), UBigInt::zero());
+        assert_eq!(UBigInt::from_bits(vec![], false), UBigInt::zero());
+
+        for n in [0, 1, 0xffff_ffff, 0x1_0000_0000, 0x1234_5678_9abc_def0_1234_5678_9abc_def0] {
+            let bits_lsb = (0..128).map(|i| (n >> i) & 1 == 1).collect::<Vec<bool>>();
+            let bits_msb = bits_lsb.iter().rev().copied().collect::<Vec<bool>>();
+
+            assert_eq!(UBigInt::from_bits(bits_lsb, false), UBigInt::from_u128(n));
+            assert_eq!(UBigInt::from_bits(bits_msb, true), UBigInt::from_u128(n));
+        }
+    }
+
+    #[test]
+    fn radix_string_test() {
+        let samples = [
+            UBigInt::zero(),
+            UBigInt::one(),
+            UBigInt::from_u32(35),
+            UBigInt::from_u32(u32::MAX),
+            UBigInt::from_u64(u64::MAX),
+            UBigInt::from_raw(vec![0, 0, 1]),
+            UBigInt::from_raw(vec![1, 2, 3, 4, 5, 6, 7]),
+            UBigInt::factorial(100),
+        ];
+
+        for n in samples.iter() {
+            for radix in 2..37 {
+                let s = n.to_string_radix(radix);
+                assert_eq!(&UBigInt::from_string_radix(&s, radix).unwrap(), n);
+                assert_eq!(&UBigInt::from_string_radix(&s.to_uppercase(), radix).unwrap(), n);
+            }
+
+            assert_eq!(n.to_string_radix(10), n.to_string_dec());
+            assert_eq!(n.to_string_radix(16), n.to_string_hex(false));
+            assert_eq!(n.to_string_radix(8), n.to_string_oct(false));
+            assert_eq!(n.to_string_radix(2), n.to_string_bin(false));
+        }
+
+        assert_eq!(UBigInt::from_u32(35).to_string_radix(36), "z");
+        assert_eq!(UBigInt::from_u32(36).to_string_radix(36), "10");
+        assert_eq!(UBigInt::from_string_radix("c0ff_ee", 16).unwrap(), UBigInt::from_u32(0xc0ffee));
+        assert_eq!(UBigInt::from_string_radix("12", 2), Err(ConversionError::InvalidChar('2')));
+        assert_eq!(UBigInt::from_string_radix("", 2), Err(ConversionError::NoData));
+        assert_eq!(UBigInt::from_string_radix("_1", 2), Err(ConversionError::InvalidChar('_')));
+    }
+
+    #[test]
+    fn from_scientific_test() {
+        for (s, answer) in [
+            ("1.5e3", UBigInt::from_u32(1500)),
+            ("1.5e1", UBigInt::from_u32(15)),
+            ("1500", UBigInt::from_u32(1500)),
+            ("0x10", UBigInt::from_u32(16)),
+            ("1.23456789E8", UBigInt::from_u32(123456789)),
+            ("12300e-2", UBigInt::from_u32(123)),
+            ("0.0e5", UBigInt::zero()),
+            ("-0", UBigInt::zero()),
+            ("1e40", UBigInt::from_u32(10).pow_u32(40)),
+        ] {
+            assert_eq!(UBigInt::from_scientific(s), Ok(answer));
+        }
+
+        for s in ["1.55e1", "1e-1", "-1.5e3", "0.5"] {
+            assert_eq!(
+                UBigInt::from_scientific(s),
+                Err(ConversionError::NotInRange { permitted: "non-negative integer".to_string(), error: s.to_string() }),
+            );
+        }
+
+        assert_eq!(UBigInt::from_scientific(""), Err(ConversionError::NoData));
+        assert!(UBigInt::from_scientific("1.5f3").is_err());
+        assert!(UBigInt::from_scientific("1.5e").is_err());
+    }
+
     #[test]
     fn string_conversion_test() {
         if !RUN_ALL_TESTS { return; }
@@ -0,0 +1,53 @@
+use crate::UBigInt;
+use crate::utils::remove_suffix_0;
+use num_bigint::BigUint;
+
+impl UBigInt {
+
+    pub fn to_num_bigint(&self) -> BigUint {
+        BigUint::from_slice(&self.0)
+    }
+
+    pub fn from_num_bigint(n: &BigUint) -> Self {
+        let mut result = n.to_u32_digits();
+
+        // `to_u32_digits` returns an empty vector for 0
+        if result.is_empty() {
+            return UBigInt::zero();
+        }
+
+        remove_suffix_0(&mut result);
+        let result = UBigInt::from_raw(result);
+
+        #[cfg(test)] assert!(result.is_valid());
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UBigInt;
+    use crate::utils::rand_u32;
+    use num_bigint::BigUint;
+
+    #[test]
+    fn num_bigint_test() {
+        assert_eq!(UBigInt::zero().to_num_bigint(), BigUint::from(0u32));
+        assert_eq!(UBigInt::from_num_bigint(&BigUint::from(0u32)), UBigInt::zero());
+        assert_eq!(UBigInt::from_u64(u64::MAX).to_num_bigint(), BigUint::from(u64::MAX));
+
+        let mut seed = 47u32;
+
+        for len in 1..40 {
+            let n = UBigInt::from_raw((0..len).map(|_| rand_u32(&mut seed) | 1).collect());
+            let m = n.to_num_bigint();
+
+            assert_eq!(m.to_string(), n.to_string_dec());
+            assert_eq!(UBigInt::from_num_bigint(&m), n);
+
+            let m = m.pow(3) + 1u32;
+            assert_eq!(UBigInt::from_num_bigint(&m), n.pow_u32(3).add_u32(1));
+        }
+    }
+}
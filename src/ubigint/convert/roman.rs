@@ -0,0 +1,114 @@
+use crate::{ConversionError, UBigInt};
+
+const ROMAN_DIGITS: [(u32, &str); 13] = [
+    (1000, "M"), (900, "CM"), (500, "D"), (400, "CD"),
+    (100, "C"), (90, "XC"), (50, "L"), (40, "XL"),
+    (10, "X"), (9, "IX"), (5, "V"), (4, "IV"),
+    (1, "I"),
+];
+
+impl UBigInt {
+
+    /// 1994 -> "MCMXCIV"\
+    /// It returns `None` if `self` is not in range 1..=3999.
+    pub fn to_roman(&self) -> Option<String> {
+        let mut n = match self.to_u32() {
+            Ok(n) if (1..4000).contains(&n) => n,
+            _ => { return None; },
+        };
+
+        let mut result = String::new();
+
+        for (value, digits) in ROMAN_DIGITS.iter() {
+            while n >= *value {
+                result.push_str(digits);
+                n -= value;
+            }
+        }
+
+        Some(result)
+    }
+
+    /// "MCMXCIV" -> 1994\
+    /// It only accepts the canonical (uppercase, subtractive) form: "IIII", "VV" and "IC" are rejected.
+    pub fn from_roman(s: &str) -> Result<Self, ConversionError> {
+        if s.is_empty() {
+            return Err(ConversionError::NoData);
+        }
+
+        let mut result = 0;
+        let mut rest = s;
+
+        for (value, digits) in ROMAN_DIGITS.iter() {
+            while let Some(r) = rest.strip_prefix(digits) {
+                result += value;
+
+                // it stops here, so that a long string doesn't overflow `result`
+                if result > 3999 {
+                    return Err(ConversionError::InvalidChar(digits.chars().next().unwrap()));
+                }
+
+                rest = r;
+            }
+        }
+
+        if let Some(c) = rest.chars().next() {
+            return Err(ConversionError::InvalidChar(c));
+        }
+
+        // the greedy parser above accepts some non-canonical forms, like "IIII" and "VV"
+        // -> compare it with the canonical form and find the first character that's different
+        let canonical = UBigInt::from_u32(result).to_roman().unwrap_or_default();
+
+        if canonical != s {
+            let invalid_char = s.chars().zip(canonical.chars()).find(
+                |(c1, c2)| c1 != c2
+            ).map(|(c, _)| c).or_else(|| s.chars().nth(canonical.len())).unwrap_or_else(|| s.chars().last().unwrap());
+
+            return Err(ConversionError::InvalidChar(invalid_char));
+        }
+
+        Ok(UBigInt::from_u32(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ConversionError, UBigInt};
+
+    #[test]
+    fn roman_test() {
+        for n in 1..4000 {
+            let n = UBigInt::from_u32(n);
+            assert_eq!(UBigInt::from_roman(&n.to_roman().unwrap()), Ok(n));
+        }
+
+        let samples = [
+            (1, "I"), (4, "IV"), (9, "IX"), (14, "XIV"), (40, "XL"),
+            (90, "XC"), (400, "CD"), (1994, "MCMXCIV"), (2024, "MMXXIV"), (3999, "MMMCMXCIX"),
+        ];
+
+        for (n, s) in samples.into_iter() {
+            assert_eq!(UBigInt::from_u32(n).to_roman().unwrap(), s);
+            assert_eq!(UBigInt::from_roman(s), Ok(UBigInt::from_u32(n)));
+        }
+
+        assert_eq!(UBigInt::zero().to_roman(), None);
+        assert_eq!(UBigInt::from_u32(4000).to_roman(), None);
+        assert_eq!(UBigInt::from_u64(u64::MAX).to_roman(), None);
+
+        for invalid in ["IIII", "VV", "IC", "XM", "MMMM", "IIV", "VX", "LL", "DD", "CCCC", "XIIII", "IXI", "iv", "ABC", "X V"] {
+            assert!(UBigInt::from_roman(invalid).is_err(), "{invalid}");
+        }
+
+        assert_eq!(UBigInt::from_roman(""), Err(ConversionError::NoData));
+        assert_eq!(UBigInt::from_roman("XA"), Err(ConversionError::InvalidChar('A')));
+        assert_eq!(UBigInt::from_roman("IIII"), Err(ConversionError::InvalidChar('I')));
+        assert_eq!(UBigInt::from_roman("VV"), Err(ConversionError::InvalidChar('V')));
+        assert_eq!(UBigInt::from_roman("MMMM"), Err(ConversionError::InvalidChar('M')));
+        assert_eq!(UBigInt::from_roman("MMMCMXCIXI"), Err(ConversionError::InvalidChar('I')));
+
+        // it would overflow u32 if it didn't stop at 3999
+        assert_eq!(UBigInt::from_roman(&"M".repeat(5_000_000)), Err(ConversionError::InvalidChar('M')));
+    }
+}
@@ -0,0 +1,45 @@
+use crate::UBigInt;
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+/// It's serialized to a decimal string, like `"12345"`.
+impl Serialize for UBigInt {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string_dec())
+    }
+}
+
+/// It accepts the strings that `UBigInt::from_string` accepts.
+impl<'de> Deserialize<'de> for UBigInt {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+
+        UBigInt::from_string(&s).map_err(|e| de::Error::custom(format!("invalid UBigInt {s:?}: {e:?}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UBigInt;
+
+    #[test]
+    fn serde_test() {
+        let numbers = [
+            UBigInt::zero(),
+            UBigInt::from_u32(12345),
+            UBigInt::from_u128(u128::MAX),
+            UBigInt::from_u32(3).pow_u32(200),
+        ];
+
+        for n in numbers.iter() {
+            let json = serde_json::to_string(n).unwrap();
+
+            assert_eq!(json, format!("\"{}\"", n.to_string_dec()));
+            assert_eq!(&serde_json::from_str::<UBigInt>(&json).unwrap(), n);
+        }
+
+        assert_eq!(serde_json::from_str::<Vec<UBigInt>>("[\"0x10\", \"7\"]").unwrap(), vec![UBigInt::from_u32(16), UBigInt::from_u32(7)]);
+        assert!(serde_json::from_str::<UBigInt>("\"-3\"").is_err());
+        assert!(serde_json::from_str::<UBigInt>("\"1.5\"").is_err());
+        assert!(serde_json::from_str::<UBigInt>("3").is_err());
+    }
+}
@@ -1,9 +1,18 @@
 use super::UBigInt;
+use crate::{BigInt, gcd_ext_bi};
+use crate::utils::remove_suffix_0;
+use crate::ubigint::sieve::sieve;
+use std::cmp::Ordering;
 
+mod barrett;
+mod bit;
 mod exp;
 pub mod log;
+mod mersenne;
+mod rho;
 mod shift;
 
+pub use barrett::BarrettCtx;
 pub use log::log2_u32;
 
 impl UBigInt {
@@ -14,68 +23,123 @@ impl UBigInt {
             return UBigInt::from_u64(sqrt_u64(self.to_u64().unwrap()));
         }
 
-        let approx = sqrt_u64(self.shift_right(self.len() - 2).to_u64().unwrap());
-
-        // result = sqrt(self) - alpha, where alpha << sqrt(self)
-        // self - result^2 = 2 * alpha * sqrt(self) - alpha^2 ~= 2 * alpha * sqrt(self)
-        let mut result = UBigInt::from_u64(approx);
+        self.sqrt_newton(self.isqrt_initial_guess()).0
+    }
 
-        if self.len() % 2 != 0 {
-            result.mul_u32_mut(65536);
+    /// It returns a number that's always bigger than `sqrt(self)`, and has about 62 correct bits.\
+    /// It takes the square root of the top 125 or 126 bits of `self` and scales it to `2^(bit_length / 2)`. It's used as the starting point of `sqrt`.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn isqrt_initial_guess(&self) -> Self {
+        if self.len() < 3 {
+            return UBigInt::from_u64(sqrt_u64(self.to_u64().unwrap()) + 1);
         }
 
-        result.shift_left_mut(self.len() / 2 - 1);
+        // log2 = bit_length - 1
+        let bit_length = self.log2().to_u64().unwrap() + 1;
 
-        let mut div = self.sub(&result.mul(&result)).div_u32(2).div(&result);
-        result.add_mut(&div);
+        // self = top * 2^(2k) + rest, where top < 2^126
+        // sqrt(self) < sqrt(top + 1) * 2^k <= (isqrt(top) + 1) * 2^k
+        let k = bit_length.saturating_sub(125) / 2;
+        let mut top = self.shift_right((2 * k / 32) as usize);
+        shift_right_bits_mut(&mut top, 2 * k % 32);
 
-        loop {
-            let result_sqr = result.mul(&result);
+        let mut result = UBigInt::from_u128(top.to_u128().unwrap().isqrt() + 1);
+        result.shift_left_mut((k / 32) as usize);
+        result.mul_u32_mut(1 << (k % 32));
 
-            if self.geq(&result_sqr) {
-                div = self.sub(&result_sqr).div_u32(2).div(&result);
-                result.add_mut(&div);
-            }
+        result
+    }
 
-            else {
-                div = result_sqr.sub(&self).div_u32(2).div(&result);
-                result.sub_mut(&div);
-            }
+    // (truncate(sqrt(self)), number of iterations)
+    // `seed` has to be bigger than or equal to `truncate(sqrt(self))`
+    fn sqrt_newton(&self, seed: Self) -> (Self, usize) {
+        let mut result = seed;
+        let mut iterations = 0;
+
+        // x' = (x + self / x) / 2
+        // starting from a number bigger than the result, it decreases monotonically until it reaches the result
+        loop {
+            let mut next = self.div(&result);
+            next.add_mut(&result);
+            next.div_u32_mut(2);
+            iterations += 1;
 
-            if div.len() < 2 {
+            if next.geq(&result) {
                 break;
             }
+
+            result = next;
         }
 
-        // Safety: div.len() < 2;
-        let mut div = div.to_u32().unwrap();
+        #[cfg(test)] {
+            assert!(result.mul(&result).leq(self));
+            assert!(result.add_u32(1).mul(&result.add_u32(1)).gt(self));
+        }
 
-        if div == 0 {
-            div = 2;
+        (result, iterations)
+    }
+
+    /// It returns `truncate(self^(1/n))`. It panics when `n` is 0.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn nth_root(&self, n: u32) -> Self {
+        if n == 0 {
+            panic!("Attempt to get the 0th root of a number: {self}");
+        }
+
+        if n == 1 || self.lt_u32(2) {
+            return self.clone();
+        }
+
+        // 2^(truncate(log2(self) / n) + 1) is always bigger than the result
+        let log2 = self.log2().to_u64().unwrap();
+
+        if log2 < n as u64 {
+            return UBigInt::one();
         }
 
+        let mut result = UBigInt::pow2((log2 / n as u64) as u32 + 1);
+
+        // x' = ((n - 1) * x + self / x^(n - 1)) / n
+        // starting from a number bigger than the result, it decreases monotonically until it reaches the result
         loop {
-            while result.mul(&result).lt(self) {
-                result.add_u32_mut(div);
+            let mut next = result.mul_u32(n - 1);
+            next.add_mut(&self.div(&result.pow_u32(n - 1)));
+            next.div_u32_mut(n);
+
+            if next.geq(&result) {
+                break;
             }
 
-            div /= 4;
+            result = next;
+        }
 
-            // it guarantees that the result is always `truncate(sqrt(self))`, not `round(sqrt(self))`
-            if div < 4 {
-                div = 1;
-            }
+        #[cfg(test)] {
+            assert!(result.pow_u32(n).leq(self));
+            assert!(result.add_u32(1).pow_u32(n).gt(self));
+        }
 
-            while result.mul(&result).gt(self) {
-                result.sub_u32_mut(div);
-            }
+        result
+    }
+
+    /// It returns `Some((base, exp))` if `self = base^exp` for some `exp >= 2`. If there are multiple candidates, it returns the one with the biggest `exp`: 64 -> (2, 6), not (4, 3) or (8, 2).\
+    /// It returns `None` for 0 and 1, because any `exp` works for them.
+    pub fn is_perfect_power(&self) -> Option<(UBigInt, u32)> {
+        if self.lt_u32(2) {
+            return None;
+        }
+
+        // `base` is at least 2, so `exp` is at most log2(self)
+        let max_exp = self.log2().to_u32().unwrap();
 
-            div /= 4;
+        for exp in (2..(max_exp + 1)).rev() {
+            let base = self.nth_root(exp);
 
-            if div == 0 {
-                return result;
+            if base.pow_u32(exp).eq(self) {
+                return Some((base, exp));
             }
         }
+
+        None
     }
 
     pub fn factorial(n: u32) -> UBigInt {
@@ -135,6 +199,17 @@ impl UBigInt {
         }
     }
 
+    /// n!! = n * (n - 2) * (n - 4) * ...\
+    /// 0!! = 1!! = 1
+    pub fn double_factorial(n: u32) -> UBigInt {
+        product_u32((2 - (n & 1)..=n).step_by(2))
+    }
+
+    /// product of all the primes less than or equal to `n`
+    pub fn primorial(n: u32) -> UBigInt {
+        product_u32(sieve(n.saturating_add(1)).into_iter())
+    }
+
     pub fn fibonacci(n: u32) -> UBigInt {
         if n < 14 {
             UBigInt::from_u32([
@@ -172,6 +247,138 @@ impl UBigInt {
         }
     }
 
+    /// self^exp % modulus\
+    /// It never computes `self^exp` itself: it reduces the result after every multiplication. It panics when `modulus` is 0.\
+    /// If you call it many times with the same `modulus`, use `pow_mod_ctx` instead.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn pow_mod(&self, exp: &UBigInt, modulus: &UBigInt) -> Self {
+        if modulus.is_zero() {
+            panic!("Attempt to divide by zero: {self:?}^{exp:?} % 0");
+        }
+
+        let base = self.rem(modulus);
+        let mut result = UBigInt::one().rem(modulus);
+
+        // from the most significant bit
+        for limb in exp.0.iter().rev() {
+            for i in (0..32).rev() {
+                result = result.mul(&result).rem(modulus);
+
+                if limb & (1 << i) != 0 {
+                    result = result.mul(&base).rem(modulus);
+                }
+            }
+        }
+
+        #[cfg(test)] assert!(result.is_valid());
+
+        result
+    }
+
+    /// It returns `x` where `self * x % modulus == 1 % modulus` and `0 <= x < modulus`.\
+    /// It returns `None` if `self` and `modulus` are not coprime. It panics when `modulus` is 0.
+    pub fn mod_inv(&self, modulus: &UBigInt) -> Option<Self> {
+        if modulus.is_zero() {
+            panic!("Attempt to divide by zero: mod_inv({self:?}, 0)");
+        }
+
+        let m = BigInt::from_ubi(modulus.clone(), false);
+        let (g, x, _) = gcd_ext_bi(&BigInt::from_ubi(self.clone(), false), &m);
+
+        if !g.is_one() {
+            return None;
+        }
+
+        // `x` may be negative
+        let mut x = x.rem(&m);
+
+        if x.is_neg() {
+            x.add_mut(&m);
+        }
+
+        let result = x.to_ubi().unwrap();
+
+        #[cfg(test)] assert_eq!(self.mul(&result).rem(modulus), UBigInt::one().rem(modulus));
+
+        Some(result)
+    }
+
+    /// It's the same as `UBigInt::factorial`, but the partial products are computed in parallel, and combined in a product tree.
+    #[cfg(feature = "rayon")]
+    pub fn factorial_parallel(n: u32) -> UBigInt {
+        use rayon::prelude::*;
+
+        // for small `n`, the overhead of threads is bigger than the gain
+        if n < 1024 {
+            return UBigInt::factorial(n);
+        }
+
+        (2..(n + 1)).into_par_iter().with_min_len(256).fold(
+            UBigInt::one,
+            |mut acc, i| { acc.mul_u32_mut(i); acc },
+        ).reduce(
+            UBigInt::one,
+            |a, b| a.mul(&b),
+        )
+    }
+
+    /// n! % modulus\
+    /// It panics when `modulus` is 0.
+    pub fn factorial_mod(n: u32, modulus: &UBigInt) -> UBigInt {
+        if modulus.is_zero() {
+            panic!("Attempt to divide by zero: {n}! % 0");
+        }
+
+        if let Ok(m) = modulus.to_u32() {
+            let m = m as u64;
+            let mut result = 1 % m;
+
+            for i in 2..(n as u64 + 1) {
+                if result == 0 {
+                    break;
+                }
+
+                result = result * (i % m) % m;
+            }
+
+            return UBigInt::from_u64(result);
+        }
+
+        let mut result = UBigInt::one();
+
+        for i in 2..(n + 1) {
+            if result.is_zero() {
+                break;
+            }
+
+            result.mul_u32_mut(i);
+            result.rem_mut(modulus);
+        }
+
+        result
+    }
+
+    /// It returns `n! / (k! * (n - k)!)`. It returns 0 when `k > n`.
+    pub fn binomial(n: u32, k: u32) -> UBigInt {
+        if k > n {
+            return UBigInt::zero();
+        }
+
+        // C(n, k) = C(n, n - k)
+        let k = k.min(n - k);
+        let mut result = UBigInt::one();
+
+        // result = C(n - k + i, i) after each step, which is always an integer
+        for i in 1..(k + 1) {
+            result.mul_u32_mut(n - k + i);
+            result.div_u32_mut(i);
+        }
+
+        result
+    }
+
+    /// It uses trial division for numbers less than 2^32, and Miller-Rabin test (`is_prime_mr`) for bigger ones.\
+    /// The result is exact for numbers less than 3317044064679887385961981. For bigger numbers, there's a very small chance that it returns true for a composite number.
     pub fn is_prime(&self) -> bool {
         if self.0[0] % 2 == 0 {
             if self.0[0] == 2 && self.0.len() == 1 {
@@ -188,63 +395,112 @@ impl UBigInt {
             false
         }
 
+        else if self.len() > 1 {
+            self.is_prime_mr(MR_ROUNDS)
+        }
+
         else {
-            let iter_until = self.sqrt().add_u32(1);
+            // Safety: self < 2^32
+            let self_ = self.to_u64().unwrap();
             let mut div = 3;
 
-            match iter_until.to_u32() {
-                Ok(n) => {
+            // div * div <= self_, without overflow
+            while div <= self_ / div {
+                if self_ % div == 0 {
+                    return false;
+                }
 
-                    // Safety: if (sqrt(self) + 1) is smaller than (2^32 - 1), self is smaller than (2^64 - 1)
-                    let self_ = self.to_u64().unwrap();
+                div += 2;
+            }
 
-                    while div < n {
-                        if self_ % div as u64 == 0 {
-                            return false;
-                        }
+            true
+        }
+    }
 
-                        div += 2;
-                    }
+    /// Miller-Rabin primality test.\
+    /// For numbers less than 3317044064679887385961981, it uses a fixed set of witnesses that makes the result exact, and `rounds` is ignored.
+    /// For bigger numbers, it additionally tests `rounds` pseudo-random witnesses. A composite number passes a round with a probability less than 1/4.
+    pub fn is_prime_mr(&self, rounds: usize) -> bool {
+        if self.lt_u32(2) {
+            return false;
+        }
 
-                    true
-                },
-                _ => {
-                    for _ in 0..(u32::MAX / 2 - 1) {
+        for p in MR_WITNESSES.iter() {
+            if self.rem_u32(*p).is_zero() {
+                return self.eq_u32(*p);
+            }
+        }
 
-                        if self.rem_u32(div).is_zero() {
-                            return false;
-                        }
+        // self - 1 = d * 2^s
+        let n_minus_1 = self.sub_u32(1);
+        let mut zero_limbs = 0;
 
-                        div += 2;
-                    }
+        while n_minus_1.0[zero_limbs] == 0 {
+            zero_limbs += 1;
+        }
 
-                    let mut div = u32::MAX as u64;
-                    div = div + div % 2 + 1;
+        let trailing_zeros = n_minus_1.0[zero_limbs].trailing_zeros();
+        let d = n_minus_1.shift_right(zero_limbs).div_u32(1 << trailing_zeros);
+        let s = zero_limbs as u32 * 32 + trailing_zeros;
 
-                    let mut div = UBigInt::from_u64(div);
+        // `a` is a witness of compositeness if a^d != 1 and a^(d * 2^r) != -1 for all 0 <= r < s
+        let is_witness = |a: &UBigInt| {
+            let mut x = a.pow_mod(&d, self);
 
-                    // TODO: this loop is not tested
-                    while div.mul(&div).leq(self) {
-                        if self.rem(&div).is_zero() {
-                            return false;
-                        }
+            if x.is_one() || x == n_minus_1 {
+                return false;
+            }
 
-                        div.add_u32_mut(2);
-                    }
+            for _ in 1..s {
+                x = x.mul(&x).rem(self);
 
-                    true
-                },
+                if x == n_minus_1 {
+                    return false;
+                }
+            }
+
+            true
+        };
+
+        if MR_WITNESSES.iter().any(|a| is_witness(&UBigInt::from_u32(*a))) {
+            return false;
+        }
+
+        if self.lt(&UBigInt::from_raw(MR_DETERMINISTIC_LIMIT.to_vec())) {
+            return true;
+        }
+
+        // pseudo-random witnesses in range 2..(self - 1)
+        let mut seed = self.0[0] as u64 ^ 0x9e37_79b9_7f4a_7c15;
+        let range = self.sub_u32(3);
+
+        for _ in 0..rounds {
+            let mut a = (0..self.len()).map(|_| {
+                seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                (seed >> 32) as u32
+            }).collect::<Vec<u32>>();
+            remove_suffix_0(&mut a);
+
+            let a = UBigInt::from_raw(a).rem(&range).add_u32(2);
+
+            if is_witness(&a) {
+                return false;
             }
         }
+
+        true
     }
 
     pub fn prime_factorial(&self) -> Vec<Self> {
         let mut self_clone = self.clone();
         let mut result = vec![];
 
-        while self_clone.gt_u32(1) && self_clone.rem_pow2(2).is_zero() {
-            self_clone.div_u32_mut(2);
-            result.push(UBigInt::from_u32(2));
+        if !self_clone.is_zero() {
+            let twos = self_clone.trailing_zeros();
+
+            self_clone.shift_right_mut(twos as usize / 32);
+            self_clone.div_u32_mut(1 << (twos % 32));
+            result.extend((0..twos).map(|_| UBigInt::from_u32(2)));
         }
 
         let mut div = 3;
@@ -262,8 +518,14 @@ impl UBigInt {
 
         // TODO: this loop is not tested
         while self_clone.geq(&div.mul(&div)) {
-            while self_clone.rem(&div).is_zero() {
-                self_clone.div_mut(&div);
+            loop {
+                let (quotient, remainder) = self_clone.div_rem(&div);
+
+                if !remainder.is_zero() {
+                    break;
+                }
+
+                self_clone = quotient;
                 result.push(div.clone());
             }
 
@@ -277,146 +539,801 @@ impl UBigInt {
         result
     }
 
-    /// It returns a random number between 1..2^(32 * scale).\
-    /// If `scale` is 0, it returns 0.
-    #[cfg(feature = "rand")]
-    pub fn random(scale: usize) -> Self {
-        if scale == 0 {
-            UBigInt::zero()
-        }
-
-        else {
-            UBigInt::from_raw(
-                (0..scale).map(|_| rand::random::<u32>().max(1)).collect()
-            )
+    /// It returns `(prime, exponent)` pairs of `self`, sorted by prime.\
+    /// It returns an empty vector when `self` is 1, and `[(0, 1)]` when `self` is 0.
+    pub fn factorize(&self) -> Vec<(UBigInt, u32)> {
+        if self.is_one() {
+            return vec![];
         }
-    }
-}
 
-fn sqrt_u64(n: u64) -> u64 {
-    if n == 0 { return 0; }
+        let mut result: Vec<(UBigInt, u32)> = vec![];
 
-    let l2 = n.ilog2();
+        // `prime_factorial` returns the factors in ascending order
+        for factor in self.prime_factorial().into_iter() {
+            match result.last_mut() {
+                Some((prime, exp)) if prime == &factor => { *exp += 1; },
+                _ => { result.push((factor, 1)); },
+            }
+        }
 
-    let mut frac = if l2 > 28 {
-        n >> (l2 - 28)
+        result
     }
 
-    else {
-        n << (28 - l2)
-    };
+    /// Möbius function: 0 if `self` has a squared prime factor, otherwise `(-1)^k` where `k` is the number of prime factors.\
+    /// `mobius(1)` is 1. It panics when `self` is 0.
+    pub fn mobius(&self) -> i32 {
+        if self.is_zero() {
+            panic!("Attempt to calculate the Möbius function of 0");
+        }
 
-    #[cfg(test)] assert!((1 << 28) <= frac && frac < (1 << 29));
+        let factors = self.factorize();
 
-    // n = 2^l2 * frac / 2^28
-    // frac = 1.xxx
-    // sqrt(1 + x) = 1 + x/2 - x^2/8
-    frac -= 1 << 28;
-    let mut s = (1 << 28) + frac / 2 - frac * frac / (1 << 31);  // sqrt(frac / 2^28) * 2^28
+        if factors.iter().any(|(_, exp)| *exp > 1) {
+            0
+        }
 
-    if l2 % 2 == 1 {
-        s *= 379625062;  // 2^28.5
-        s /= 1 << 28;
-    }
+        else if factors.len() & 1 == 0 {
+            1
+        }
 
-    let d = (1 << (l2 / 2)) * s;
+        else {
+            -1
+        }
+    }
 
-    let mut result = d / (1 << 28);
+    /// Liouville function: `(-1)^k` where `k` is the number of prime factors, counted with multiplicity.\
+    /// `liouville(1)` is 1. It panics when `self` is 0.
+    pub fn liouville(&self) -> i32 {
+        if self.is_zero() {
+            panic!("Attempt to calculate the Liouville function of 0");
+        }
 
-    // see the comments in `UBigInt::sqrt`
-    let mut div = (n.abs_diff(result * result) / (2 * result)).max(1);
+        let count = self.factorize().iter().map(|(_, exp)| *exp as u64).sum::<u64>();
 
-    if n > result * result {
-        result += div;
+        if count & 1 == 0 { 1 } else { -1 }
     }
 
-    else {
-        result -= div;
-    }
+    /// `self % other == 0`\
+    /// If `other` is 0, it returns true only when `self` is 0.
+    pub fn is_multiple_of(&self, other: &UBigInt) -> bool {
+        if other.is_zero() {
+            self.is_zero()
+        }
 
-    if result == 0 {
-        div = 2;
+        else {
+            self.rem(other).is_zero()
+        }
     }
 
-    else {
-        div = n.abs_diff(result * result) / (2 * result);
+    /// It returns the smallest multiple of `other` that is greater than or equal to `self`.\
+    /// It panics when `other` is 0.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn next_multiple_of(&self, other: &UBigInt) -> Self {
+        if other.is_zero() {
+            panic!("Attempt to divide by zero: next_multiple_of({self:?}, 0)");
+        }
 
-        if div == 0 {
-            div = 2;
+        let rem = self.rem(other);
+
+        if rem.is_zero() {
+            self.clone()
+        }
+
+        else {
+            self.add(&other.sub(&rem))
         }
     }
 
-    loop {
-        while result * result < n {
-            result += div;
+    /// number of the low-order zero digits in decimal: 12000 -> 3\
+    /// It panics when `self` is 0.
+    pub fn trailing_zeros_base10(&self) -> u64 {
+        if self.is_zero() {
+            panic!("Attempt to count the trailing zeros of 0");
         }
 
-        div /= 4;
+        let mut result = 0;
+        let mut curr = self.clone();
 
-        if div < 4 { div = 1; }
+        // 9 digits at a time
+        loop {
+            let (quotient, remainder) = curr.div_rem_u32(1_000_000_000);
 
-        while result * result > n {
-            result -= div;
+            if remainder != 0 {
+                break;
+            }
+
+            curr = quotient;
+            result += 9;
         }
 
-        div /= 4;
+        while curr.rem_u32(10).is_zero() {
+            curr.div_u32_mut(10);
+            result += 1;
+        }
 
-        if div == 0 {
-            #[cfg(test)] assert!(result * result <= n && n <= (result + 1) * (result + 1));
+        result
+    }
 
-            return result;
+    /// It returns the number of steps of the Collatz sequence to reach 1. It returns 0 for 1.\
+    /// It panics when `self` is 0.
+    pub fn collatz_steps(&self) -> u64 {
+        if self.is_zero() {
+            panic!("Collatz sequence of 0 never reaches 1");
         }
-    }
-}
 
-pub fn gcd_ubi(a: &UBigInt, b: &UBigInt) -> UBigInt {
-    if a.is_zero() {
-        return b.clone();
-    }
+        let mut n = self.clone();
+        let mut steps = 0;
 
-    let _a = a.clone();
-    let mut a = b.rem(a);
-    let mut b = _a;
+        loop {
+            // n /= 2^32 while the lowest limb is 0
+            let mut zero_limbs = 0;
 
-    while !a.is_zero() {
-        let _a = a.clone();
-        a = b.rem(&a);
-        b = _a;
-    }
+            while n.0[zero_limbs] == 0 {
+                zero_limbs += 1;
+            }
 
-    b
-}
+            if zero_limbs > 0 {
+                n.shift_right_mut(zero_limbs);
+                steps += zero_limbs as u64 * 32;
+            }
 
-#[cfg(test)]
-mod tests {
-    use crate::UBigInt;
-    use crate::consts::RUN_ALL_TESTS;
-    use super::sqrt_u64;
+            // n /= 2^k where 2^k is the largest power of 2 that divides the lowest limb
+            let trailing_zeros = n.0[0].trailing_zeros();
 
-    #[test]
-    fn factorial_test() {
-        if !RUN_ALL_TESTS { return; }
+            if trailing_zeros > 0 {
+                n.div_u32_mut(1 << trailing_zeros);
+                steps += trailing_zeros as u64;
+            }
 
-        let mut acc = UBigInt::one();
+            if n.is_one() {
+                return steps;
+            }
 
-        for n in 2..256 {
-            acc.mul_u32_mut(n);
-            assert_eq!(UBigInt::factorial(n), acc);
+            n.mul_u32_mut(3);
+            n.add_u32_mut(1);
+            steps += 1;
         }
     }
 
-    #[test]
-    fn fibonacci_test() {
-        if !RUN_ALL_TESTS { return; }
+    /// It returns the smallest prime that divides `self` and is less than or equal to `bound`. It returns `None` if there's no such prime.\
+    /// It's a cheap filter to run before expensive primality tests or factorizations.
+    pub fn smallest_prime_factor_below(&self, bound: u32) -> Option<u32> {
+        for p in SMALL_PRIMES.iter() {
+            if *p > bound {
+                return None;
+            }
 
-        let mut fibos = vec![
-            UBigInt::zero(), UBigInt::one(), UBigInt::one()
-        ];
+            if self.rem_u32(*p).is_zero() {
+                return Some(*p);
+            }
+        }
 
-        for i in 3..256 {
-            fibos.push(
-                fibos[i - 1].add(&fibos[i - 2])
-            );
+        if self.is_one() {
+            return None;
+        }
+
+        // the first odd number that divides `self` is always a prime
+        let mut div = SMALL_PRIMES[SMALL_PRIMES.len() - 1] + 2;
+
+        while div <= bound {
+            // `self` has no factor less than or equal to sqrt(self), so `self` is a prime
+            if self.lt(&UBigInt::from_u64(div as u64 * div as u64)) {
+                return self.to_u32().ok().filter(|n| *n <= bound);
+            }
+
+            if self.rem_u32(div).is_zero() {
+                return Some(div);
+            }
+
+            div = div.checked_add(2)?;
+        }
+
+        None
+    }
+
+    /// It returns `(a, b)` where `a * a + b * b == self` and `a <= b`.\
+    /// It returns `None` if `self` cannot be written as a sum of two squares, which is when a prime factor of form `4k + 3` appears an odd number of times.
+    pub fn sum_of_two_squares(&self) -> Option<(UBigInt, UBigInt)> {
+        if self.is_zero() {
+            return Some((UBigInt::zero(), UBigInt::zero()));
+        }
+
+        let factors = self.prime_factorial();
+        let mut a = UBigInt::zero();
+        let mut b = UBigInt::one();
+        let mut i = 0;
+
+        while i < factors.len() {
+            let p = &factors[i];
+            let mut exp = 0;
+
+            while i < factors.len() && &factors[i] == p {
+                exp += 1;
+                i += 1;
+            }
+
+            if p.is_one() {
+                continue;
+            }
+
+            // p = 4k + 3 -> p^(2e) = (p^e)^2 + 0^2
+            if p.rem_u32(4).to_u32().unwrap() == 3 {
+                if exp % 2 == 1 {
+                    return None;
+                }
+
+                let p_pow = p.pow_u32(exp / 2);
+                a.mul_mut(&p_pow);
+                b.mul_mut(&p_pow);
+                continue;
+            }
+
+            let (c, d) = prime_sum_of_two_squares(p);
+
+            // (a^2 + b^2)(c^2 + d^2) = (ac - bd)^2 + (ad + bc)^2
+            for _ in 0..exp {
+                let ac = a.mul(&c);
+                let bd = b.mul(&d);
+                let ad_bc = a.mul(&d).add(&b.mul(&c));
+
+                a = if ac.geq(&bd) { ac.sub(&bd) } else { bd.sub(&ac) };
+                b = ad_bc;
+            }
+        }
+
+        #[cfg(test)] assert_eq!(&a.mul(&a).add(&b.mul(&b)), self);
+
+        if a.gt(&b) {
+            Some((b, a))
+        }
+
+        else {
+            Some((a, b))
+        }
+    }
+
+    /// Wilson's theorem: `n` is a prime iff `(n - 1)! % n == n - 1`.\
+    /// It's exact but takes O(n) multiplications, so it's only for cross-checking other primality tests.
+    /// It panics when `self` is greater than `2^32`.
+    pub fn is_prime_wilson(&self) -> bool {
+        if self.lt_u32(2) {
+            return false;
+        }
+
+        let n_minus_1 = self.sub_u32(1);
+
+        UBigInt::factorial_mod(n_minus_1.to_u32().unwrap(), self) == n_minus_1
+    }
+
+    /// gcd(self, other), using machine-word Euclid after a single `rem_u32`.\
+    /// If `other` is 0, the gcd is `self`, so it panics when `self` doesn't fit in u32.
+    pub fn gcd_u32(&self, other: u32) -> u32 {
+        if other == 0 {
+            return self.to_u32().unwrap();
+        }
+
+        let mut a = self.rem_u32(other).0[0];
+        let mut b = other;
+
+        while a != 0 {
+            let r = b % a;
+            b = a;
+            a = r;
+        }
+
+        b
+    }
+
+    /// truncate((self + other) / 2)\
+    /// It computes `a + (b - a) / 2` where `a <= b`, so it never builds the sum `a + b`.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn midpoint(&self, other: &UBigInt) -> Self {
+        let (a, b) = if self.leq(other) { (self, other) } else { (other, self) };
+
+        let mut result = b.sub(a);
+        shift_right_bits_mut(&mut result, 1);
+        result.add_mut(a);
+
+        #[cfg(test)] assert!(result.is_valid());
+
+        result
+    }
+
+    /// It returns `a` if `cond` else `b`, without branching on `cond`.\
+    /// Each limb is selected by a mask. The shorter one is padded with zeros, so the time it takes only depends on the lengths of `a` and `b`.
+    pub fn ct_select(cond: bool, a: &UBigInt, b: &UBigInt) -> UBigInt {
+        let mask = 0u32.wrapping_sub(cond as u32);
+        let len = a.len().max(b.len());
+
+        let mut result = (0..len).map(
+            |i| (a.0.get(i).unwrap_or(&0) & mask) | (b.0.get(i).unwrap_or(&0) & !mask)
+        ).collect::<Vec<u32>>();
+
+        remove_suffix_0(&mut result);
+
+        let result = UBigInt::from_raw(result);
+
+        #[cfg(test)] assert!(result.is_valid());
+
+        result
+    }
+
+    /// It returns a random number between 1..2^(32 * scale).\
+    /// If `scale` is 0, it returns 0.
+    #[cfg(feature = "rand")]
+    pub fn random(scale: usize) -> Self {
+        if scale == 0 {
+            UBigInt::zero()
+        }
+
+        else {
+            UBigInt::from_raw(
+                (0..scale).map(|_| rand::random::<u32>().max(1)).collect()
+            )
+        }
+    }
+}
+
+fn sqrt_u64(n: u64) -> u64 {
+    if n == 0 { return 0; }
+
+    let l2 = n.ilog2();
+
+    let mut frac = if l2 > 28 {
+        n >> (l2 - 28)
+    }
+
+    else {
+        n << (28 - l2)
+    };
+
+    #[cfg(test)] assert!((1 << 28) <= frac && frac < (1 << 29));
+
+    // n = 2^l2 * frac / 2^28
+    // frac = 1.xxx
+    // sqrt(1 + x) = 1 + x/2 - x^2/8
+    frac -= 1 << 28;
+    let mut s = (1 << 28) + frac / 2 - frac * frac / (1 << 31);  // sqrt(frac / 2^28) * 2^28
+
+    if l2 % 2 == 1 {
+        s *= 379625062;  // 2^28.5
+        s /= 1 << 28;
+    }
+
+    let d = (1 << (l2 / 2)) * s;
+
+    let mut result = d / (1 << 28);
+
+    // see the comments in `UBigInt::sqrt`
+    let mut div = (n.abs_diff(result * result) / (2 * result)).max(1);
+
+    if n > result * result {
+        result += div;
+    }
+
+    else {
+        result -= div;
+    }
+
+    if result == 0 {
+        div = 2;
+    }
+
+    else {
+        div = n.abs_diff(result * result) / (2 * result);
+
+        if div == 0 {
+            div = 2;
+        }
+    }
+
+    loop {
+        while result * result < n {
+            result += div;
+        }
+
+        div /= 4;
+
+        if div < 4 { div = 1; }
+
+        while result * result > n {
+            result -= div;
+        }
+
+        div /= 4;
+
+        if div == 0 {
+            #[cfg(test)] assert!(result * result <= n && n <= (result + 1) * (result + 1));
+
+            return result;
+        }
+    }
+}
+
+pub fn gcd_ubi(a: &UBigInt, b: &UBigInt) -> UBigInt {
+    if a.is_zero() {
+        return b.clone();
+    }
+
+    let _a = a.clone();
+    let mut a = b.rem(a);
+    let mut b = _a;
+
+    while !a.is_zero() {
+        let _a = a.clone();
+        a = b.rem(&a);
+        b = _a;
+    }
+
+    b
+}
+
+/// It returns the same value as `gcd_ubi`, but it's faster.\
+/// It uses the binary gcd algorithm, which only needs subtractions and bit shifts. When one number is much bigger than the other, it falls back to a division step.
+pub fn gcd_binary_ubi(a: &UBigInt, b: &UBigInt) -> UBigInt {
+    if a.is_zero() {
+        return b.clone();
+    }
+
+    if b.is_zero() {
+        return a.clone();
+    }
+
+    if a.len() < 3 && b.len() < 3 {
+        return UBigInt::from_u64(gcd_binary_u64(a.to_u64().unwrap(), b.to_u64().unwrap()));
+    }
+
+    // gcd(2^m * a, 2^n * b) = 2^min(m, n) * gcd(a, b) where a and b are odd
+    let a_twos = a.trailing_zeros();
+    let b_twos = b.trailing_zeros();
+    let twos = a_twos.min(b_twos);
+
+    let mut a = a.clone();
+    let mut b = b.clone();
+    shift_right_bits_mut(&mut a, a_twos);
+    shift_right_bits_mut(&mut b, b_twos);
+
+    // invariant: `a` and `b` are odd
+    let mut result = loop {
+        if a.len() < 3 && b.len() < 3 {
+            break UBigInt::from_u64(gcd_binary_u64(a.to_u64().unwrap(), b.to_u64().unwrap()));
+        }
+
+        match a.comp(&b) {
+            Ordering::Less => { std::mem::swap(&mut a, &mut b); },
+            Ordering::Equal => { break a; },
+            Ordering::Greater => {},
+        }
+
+        // a > b
+        if a.len() > b.len() + 1 {
+            a = a.rem(&b);
+
+            if a.is_zero() {
+                break b;
+            }
+        }
+
+        else {
+            a.sub_mut(&b);
+        }
+
+        let a_twos = a.trailing_zeros();
+        shift_right_bits_mut(&mut a, a_twos);
+    };
+
+    result.shift_left_mut(twos as usize / 32);
+    result.mul_u32_mut(1 << (twos % 32));
+
+    result
+}
+
+fn gcd_binary_u64(mut a: u64, mut b: u64) -> u64 {
+    if a == 0 {
+        return b;
+    }
+
+    if b == 0 {
+        return a;
+    }
+
+    let twos = (a | b).trailing_zeros();
+    a >>= a.trailing_zeros();
+
+    while b != 0 {
+        b >>= b.trailing_zeros();
+
+        if a > b {
+            std::mem::swap(&mut a, &mut b);
+        }
+
+        b -= a;
+    }
+
+    a << twos
+}
+
+// n /= 2^bits
+fn shift_right_bits_mut(n: &mut UBigInt, bits: u64) {
+    n.shift_right_mut(bits as usize / 32);
+    let bits = bits % 32;
+
+    if bits != 0 {
+        for i in 0..n.len() {
+            let upper = n.0.get(i + 1).map(|limb| limb << (32 - bits)).unwrap_or(0);
+            n.0[i] = (n.0[i] >> bits) | upper;
+        }
+
+        remove_suffix_0(&mut n.0);
+    }
+}
+
+/// It returns 0 if `a` or `b` is 0.
+pub fn lcm_ubi(a: &UBigInt, b: &UBigInt) -> UBigInt {
+    if a.is_zero() || b.is_zero() {
+        return UBigInt::zero();
+    }
+
+    // divide first, so that the intermediate value doesn't get bigger than the result
+    a.div(&gcd_ubi(a, b)).mul(b)
+}
+
+// the first 13 primes: if `n < 3317044064679887385961981`, these witnesses are enough to make Miller-Rabin deterministic
+const MR_WITNESSES: [u32; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+// 3317044064679887385961981
+const MR_DETERMINISTIC_LIMIT: [u32; 3] = [0x2410_a5fd, 0x51ad_c5b2, 0x2_be69];
+
+// number of pseudo-random witnesses `is_prime` uses for numbers above `MR_DETERMINISTIC_LIMIT`
+const MR_ROUNDS: usize = 16;
+
+// primes below 256
+const SMALL_PRIMES: [u32; 54] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37,
+    41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89,
+    97, 101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151,
+    157, 163, 167, 173, 179, 181, 191, 193, 197, 199, 211, 223,
+    227, 229, 233, 239, 241, 251,
+];
+
+// `p` is 2 or a prime of form `4k + 1`
+fn prime_sum_of_two_squares(p: &UBigInt) -> (UBigInt, UBigInt) {
+    if p.eq_u32(2) {
+        return (UBigInt::one(), UBigInt::one());
+    }
+
+    let p_minus_1 = p.sub_u32(1);
+    let exp = p_minus_1.div_u32(4);
+    let mut c = 2;
+
+    // x^2 = -1 (mod p), where x = c^((p - 1) / 4) and c is a quadratic non-residue
+    let x = loop {
+        let x = UBigInt::from_u32(c).pow_mod(&exp, p);
+
+        if x.mul(&x).rem(p) == p_minus_1 {
+            break x;
+        }
+
+        c += 1;
+    };
+
+    // Hermite-Serret: run the Euclidean algorithm on (p, x) until the remainder gets smaller than sqrt(p)
+    let mut a = p.clone();
+    let mut b = x;
+
+    while b.mul(&b).gt(p) {
+        let r = a.rem(&b);
+        a = b;
+        b = r;
+    }
+
+    let other = p.sub(&b.mul(&b)).sqrt();
+
+    (b, other)
+}
+
+// it multiplies small numbers in a `u32` buffer, and calls `mul_u32_mut` only when the buffer is full
+fn product_u32(factors: impl Iterator<Item = u32>) -> UBigInt {
+    let mut result = UBigInt::one();
+    let mut int_buffer: u32 = 1;
+
+    for i in factors {
+        match int_buffer.checked_mul(i) {
+            Some(n) => { int_buffer = n; },
+            None => {
+                result.mul_u32_mut(int_buffer);
+                int_buffer = i;
+            },
+        }
+    }
+
+    if int_buffer > 1 {
+        result.mul_u32_mut(int_buffer);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BarrettCtx, UBigInt};
+    use crate::utils::{rand_u32, rand_ubi};
+    use crate::consts::RUN_ALL_TESTS;
+    use super::sqrt_u64;
+
+    #[test]
+    fn factorial_test() {
+        if !RUN_ALL_TESTS { return; }
+
+        let mut acc = UBigInt::one();
+
+        for n in 2..256 {
+            acc.mul_u32_mut(n);
+            assert_eq!(UBigInt::factorial(n), acc);
+        }
+    }
+
+    #[test]
+    fn double_factorial_primorial_test() {
+        assert_eq!(UBigInt::double_factorial(0), UBigInt::one());
+        assert_eq!(UBigInt::double_factorial(1), UBigInt::one());
+        assert_eq!(UBigInt::double_factorial(2), UBigInt::from_u32(2));
+        assert_eq!(UBigInt::double_factorial(7), UBigInt::from_u32(105));
+        assert_eq!(UBigInt::double_factorial(8), UBigInt::from_u32(384));
+
+        // n! = n!! * (n - 1)!!
+        for n in 1..300 {
+            assert_eq!(
+                UBigInt::double_factorial(n).mul(&UBigInt::double_factorial(n - 1)),
+                UBigInt::factorial(n),
+            );
+        }
+
+        assert_eq!(UBigInt::primorial(0), UBigInt::one());
+        assert_eq!(UBigInt::primorial(1), UBigInt::one());
+        assert_eq!(UBigInt::primorial(2), UBigInt::from_u32(2));
+        assert_eq!(UBigInt::primorial(10), UBigInt::from_u32(210));
+        assert_eq!(UBigInt::primorial(11), UBigInt::from_u32(2310));
+        assert_eq!(UBigInt::primorial(12), UBigInt::from_u32(2310));
+        assert_eq!(UBigInt::primorial(47), UBigInt::from_u64(614889782588491410));
+
+        let mut acc = UBigInt::one();
+
+        for p in UBigInt::primes_below(1000) {
+            acc.mul_mut(&p);
+            assert_eq!(UBigInt::primorial(p.to_u32().unwrap()), acc);
+        }
+    }
+
+    #[test]
+    fn binomial_test() {
+        // Pascal's triangle
+        let mut row = vec![UBigInt::one()];
+
+        for n in 1..64 {
+            let mut next = vec![UBigInt::one()];
+
+            for k in 1..n {
+                next.push(row[k as usize - 1].add(&row[k as usize]));
+            }
+
+            next.push(UBigInt::one());
+            row = next;
+
+            for k in 0..(n + 1) {
+                assert_eq!(UBigInt::binomial(n, k), row[k as usize]);
+            }
+        }
+
+        assert_eq!(UBigInt::binomial(100, 50), UBigInt::from_string("100891344545564193334812497256").unwrap());
+        assert_eq!(UBigInt::binomial(1000, 500).rem_u32(1_000_000_007), UBigInt::from_u32(159835829));
+        assert_eq!(UBigInt::binomial(1000, 500), UBigInt::factorial(1000).div(&UBigInt::factorial(500).pow_u32(2)));
+
+        assert_eq!(UBigInt::binomial(5, 6), UBigInt::zero());
+        assert_eq!(UBigInt::binomial(0, 1), UBigInt::zero());
+        assert_eq!(UBigInt::binomial(0, 0), UBigInt::one());
+        assert_eq!(UBigInt::binomial(u32::MAX, 0), UBigInt::one());
+        assert_eq!(UBigInt::binomial(u32::MAX, 1), UBigInt::from_u32(u32::MAX));
+    }
+
+    #[test]
+    fn nth_root_test() {
+        let samples = vec![
+            (27, 3, 3),
+            (28, 3, 3),
+            (26, 3, 2),
+            (1024, 10, 2),
+            (1023, 10, 1),
+            (1000000, 6, 10),
+            (999999, 6, 9),
+            (12345, 1, 12345),
+            (7, 100, 1),
+            (1, 7, 1),
+            (0, 7, 0),
+        ];
+
+        for (n, k, root) in samples.into_iter() {
+            assert_eq!(UBigInt::from_u32(n).nth_root(k), UBigInt::from_u32(root));
+        }
+
+        let mut seed = 13u32;
+
+        for _ in 0..64 {
+            let r = rand_u32(&mut seed);
+            let base = UBigInt::from_raw(vec![r | 1, r >> 7]);
+            let k = r % 9 + 2;
+
+            // perfect powers, and their neighbours
+            let power = base.pow_u32(k);
+            assert_eq!(power.nth_root(k), base);
+            assert_eq!(power.sub_u32(1).nth_root(k), base.sub_u32(1));
+            assert_eq!(power.add_u32(1).nth_root(k), base);
+        }
+
+        for n in 0..1000 {
+            assert_eq!(UBigInt::from_u32(n).nth_root(2), UBigInt::from_u32(n).sqrt());
+        }
+    }
+
+    #[test]
+    fn perfect_power_test() {
+        let samples = vec![
+            (64, Some((2, 6))),
+            (63, None),
+            (81, Some((3, 4))),
+            (100, Some((10, 2))),
+            (1000, Some((10, 3))),
+            (4, Some((2, 2))),
+            (2, None),
+            (3, None),
+            (1, None),
+            (0, None),
+        ];
+
+        for (n, power) in samples.into_iter() {
+            assert_eq!(
+                UBigInt::from_u32(n).is_perfect_power(),
+                power.map(|(base, exp)| (UBigInt::from_u32(base), exp)),
+            );
+        }
+
+        assert_eq!(UBigInt::pow2(1000).is_perfect_power(), Some((UBigInt::from_u32(2), 1000)));
+        assert_eq!(UBigInt::pow2(1000).sub_u32(1).is_perfect_power(), None);
+
+        let n = UBigInt::from_u32(12).pow_u32(35);
+        assert_eq!(n.is_perfect_power(), Some((UBigInt::from_u32(12), 35)));
+        assert_eq!(UBigInt::from_u32(36).pow_u32(5).is_perfect_power(), Some((UBigInt::from_u32(6), 10)));
+        assert_eq!(n.add_u32(1).is_perfect_power(), None);
+
+        for n in 2..2000 {
+            let n = UBigInt::from_u32(n);
+
+            match n.is_perfect_power() {
+                Some((base, exp)) => {
+                    assert_eq!(base.pow_u32(exp), n);
+
+                    // the exponent is the biggest one
+                    assert!(base.is_perfect_power().is_none());
+                },
+                None => {
+                    assert!(!n.sqrt().mul(&n.sqrt()).eq(&n));
+                },
+            }
+        }
+    }
+
+    #[test]
+    fn fibonacci_test() {
+        if !RUN_ALL_TESTS { return; }
+
+        let mut fibos = vec![
+            UBigInt::zero(), UBigInt::one(), UBigInt::one()
+        ];
+
+        for i in 3..256 {
+            fibos.push(
+                fibos[i - 1].add(&fibos[i - 2])
+            );
         }
 
         for i in 0..256 {
@@ -501,6 +1418,51 @@ mod tests {
             UBigInt::from_string("400_0000_0000_0000_0000_0000_0000_0000_0000").unwrap().sqrt(),
             UBigInt::from_string("20_0000_0000_0000_0000").unwrap(),
         );
+
+        let mut seed = 23u32;
+
+        for len in [3, 4, 7, 16, 64, 256] {
+            let r = rand_u32(&mut seed);
+            let n = UBigInt::from_raw((0..len).map(|i| r.wrapping_mul(i + 1) | 1).collect());
+            let (root, iterations) = n.sqrt_newton(n.isqrt_initial_guess());
+
+            // the initial guess has about 62 correct bits, and the number of correct bits doubles in every step
+            let bits = (len * 32) as usize;
+            assert!(iterations <= bits.ilog2() as usize - 3, "{len}: {iterations}");
+
+            // the previous seed: the square root of the top 2 limbs, rounded up
+            let mut old_seed = UBigInt::from_u64(sqrt_u64(n.shift_right(n.len() - 2).to_u64().unwrap()) + 1);
+
+            if n.len() & 1 == 1 {
+                old_seed.mul_u32_mut(65536);
+            }
+
+            old_seed.shift_left_mut(n.len() / 2 - 1);
+            let (old_root, old_iterations) = n.sqrt_newton(old_seed);
+            assert_eq!(root, old_root);
+            assert!(iterations <= old_iterations, "{len}: {iterations}, {old_iterations}");
+
+            assert_eq!(root, n.sqrt());
+            assert_eq!(root.mul(&root).sqrt(), root);
+            assert_eq!(root.mul(&root).sub_u32(1).sqrt(), root.sub_u32(1));
+        }
+
+        for n in [0, 1, 2, 3, 4, 5, 16, 17, 1 << 20, u32::MAX].into_iter().map(UBigInt::from_u32).chain([UBigInt::exp2(64), UBigInt::exp2(125).sub_u32(1)]) {
+            let guess = n.isqrt_initial_guess();
+
+            assert!(guess.mul(&guess).gt(&n));
+            assert_eq!(guess, n.sqrt().add_u32(1));
+        }
+
+        for n in [UBigInt::exp2(127), UBigInt::exp2(300).sub_u32(1), UBigInt::exp2(1001), UBigInt::from_raw(vec![u32::MAX; 9])] {
+            let guess = n.isqrt_initial_guess();
+            let root = n.sqrt();
+
+            // (guess - root) / root < 2^-60
+            assert!(guess.mul(&guess).gt(&n));
+            assert!(guess.sub(&root).shift_left(2).leq(&root.mul_u32(16)));
+        }
+
         assert_eq!(
             UBigInt::from_raw(vec![0, 0x10_000_000]).sqrt(),
             UBigInt::from_u32(2).pow_u32(30),
@@ -551,6 +1513,90 @@ mod tests {
         }
     }
 
+    #[test]
+    fn factorize_test() {
+        assert_eq!(UBigInt::one().factorize(), vec![]);
+        assert_eq!(UBigInt::zero().factorize(), vec![(UBigInt::zero(), 1)]);
+        assert_eq!(
+            UBigInt::from_u32(360).factorize(),
+            vec![(UBigInt::from_u32(2), 3), (UBigInt::from_u32(3), 2), (UBigInt::from_u32(5), 1)],
+        );
+
+        for n in (2..3000).chain([1 << 31, 999_999_937, 600_851_475]) {
+            let n = UBigInt::from_u32(n);
+            let factors = n.factorize();
+            let mut product = UBigInt::one();
+
+            for (i, (p, e)) in factors.iter().enumerate() {
+                assert!(p.is_prime());
+                assert!(*e > 0);
+
+                if i > 0 {
+                    assert!(factors[i - 1].0.lt(p));
+                }
+
+                product.mul_mut(&p.pow_u32(*e));
+            }
+
+            assert_eq!(product, n);
+        }
+
+        // phi(n) = n * prod{p | n}(1 - 1/p)
+        let totient = |n: &UBigInt| {
+            n.factorize().iter().fold(
+                UBigInt::one(),
+                |acc, (p, e)| acc.mul(&p.pow_u32(e - 1)).mul(&p.sub_u32(1))
+            )
+        };
+
+        for n in 1..300u32 {
+            let answer = (1..=n).filter(|k| UBigInt::from_u32(n).gcd_u32(*k) == 1).count();
+            assert_eq!(totient(&UBigInt::from_u32(n)), UBigInt::from_u32(answer as u32));
+        }
+    }
+
+    #[test]
+    fn mobius_liouville_test() {
+        let n = |n: u32| UBigInt::from_u32(n);
+
+        assert_eq!(n(1).mobius(), 1);
+        assert_eq!(n(30).mobius(), -1);
+        assert_eq!(n(12).mobius(), 0);
+        assert_eq!(n(35).mobius(), 1);
+        assert_eq!(n(1).liouville(), 1);
+        assert_eq!(n(12).liouville(), -1);
+        assert_eq!(n(36).liouville(), 1);
+        assert_eq!(n(2).pow_u32(31).liouville(), -1);
+
+        // Mertens function M(x) and the summatory Liouville function L(x)
+        let mut mertens = 0;
+        let mut liouville = 0;
+
+        for i in 1..=1000 {
+            mertens += n(i).mobius();
+            liouville += n(i).liouville();
+
+            match i {
+                10 => { assert_eq!((mertens, liouville), (-1, 0)); },
+                100 => { assert_eq!((mertens, liouville), (1, -2)); },
+                1000 => { assert_eq!((mertens, liouville), (2, -14)); },
+                _ => {},
+            }
+        }
+
+        // sum{d | n} mobius(d) is 1 when n = 1, otherwise 0
+        for i in 1..200 {
+            let sum = (1..=i).filter(|d| n(i).is_multiple_of(&n(*d))).map(|d| n(d).mobius()).sum::<i32>();
+            assert_eq!(sum, if i == 1 { 1 } else { 0 });
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn mobius_zero_test() {
+        let _ = UBigInt::zero().mobius();
+    }
+
     fn prime_factorial_test_unit(number: &UBigInt) {
         let result = number.prime_factorial();
         let mut answer = UBigInt::one();
@@ -569,4 +1615,431 @@ mod tests {
             panic!("{number:?}, {result:?}");
         }
     }
+
+    #[test]
+    fn multiple_of_test() {
+        assert_eq!(UBigInt::from_u32(10).next_multiple_of(&UBigInt::from_u32(3)), UBigInt::from_u32(12));
+        assert_eq!(UBigInt::from_u32(12).next_multiple_of(&UBigInt::from_u32(3)), UBigInt::from_u32(12));
+        assert_eq!(UBigInt::zero().next_multiple_of(&UBigInt::from_u32(3)), UBigInt::zero());
+        assert!(UBigInt::from_u32(12).is_multiple_of(&UBigInt::from_u32(3)));
+        assert!(!UBigInt::from_u32(13).is_multiple_of(&UBigInt::from_u32(3)));
+        assert!(UBigInt::zero().is_multiple_of(&UBigInt::zero()));
+        assert!(!UBigInt::one().is_multiple_of(&UBigInt::zero()));
+
+        let big = UBigInt::from_raw(vec![7, 8, 9, 10]);
+
+        for d in [1, 2, 3, 7, 1 << 31, u32::MAX] {
+            let d = UBigInt::from_u32(d);
+            let next = big.next_multiple_of(&d);
+
+            assert!(next.is_multiple_of(&d));
+            assert!(next.geq(&big));
+            assert!(next.sub(&big).lt(&d));
+        }
+
+        let d = UBigInt::from_raw(vec![1, 2, 3]);
+        assert_eq!(big.mul(&d).next_multiple_of(&d), big.mul(&d));
+        assert_eq!(big.mul(&d).add_u32(1).next_multiple_of(&d), big.add_u32(1).mul(&d));
+    }
+
+    #[test]
+    fn trailing_zeros_base10_test() {
+        assert_eq!(UBigInt::from_u32(12000).trailing_zeros_base10(), 3);
+        assert_eq!(UBigInt::from_u32(12345).trailing_zeros_base10(), 0);
+        assert_eq!(UBigInt::from_u32(1).trailing_zeros_base10(), 0);
+        assert_eq!(UBigInt::from_u32(1_000_000_000).trailing_zeros_base10(), 9);
+
+        for n in [1, 7, 12, 999_999_999] {
+            for zeros in [0, 1, 8, 9, 10, 17, 18, 19, 100] {
+                let n = UBigInt::from_u32(n).mul(&UBigInt::from_u32(10).pow_u32(zeros));
+                assert_eq!(n.trailing_zeros_base10(), zeros as u64);
+            }
+        }
+
+        // 100! has 24 trailing zeros
+        assert_eq!(UBigInt::factorial(100).trailing_zeros_base10(), 24);
+        assert_eq!(UBigInt::from_u32(2).pow_u32(100).trailing_zeros_base10(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn trailing_zeros_base10_panic_test() {
+        let _ = UBigInt::zero().trailing_zeros_base10();
+    }
+
+    #[test]
+    fn collatz_test() {
+        for n in 1..2000u64 {
+            let mut n_ = n;
+            let mut steps = 0;
+
+            while n_ != 1 {
+                n_ = if n_ % 2 == 0 { n_ / 2 } else { 3 * n_ + 1 };
+                steps += 1;
+            }
+
+            assert_eq!(UBigInt::from_u64(n).collatz_steps(), steps);
+        }
+
+        assert_eq!(UBigInt::from_u32(27).collatz_steps(), 111);
+        assert_eq!(UBigInt::one().collatz_steps(), 0);
+        assert_eq!(UBigInt::from_u32(2).pow_u32(100).collatz_steps(), 100);
+        assert_eq!(UBigInt::from_u128((1 << 64) + 1).collatz_steps(), 483);
+        assert_eq!(UBigInt::from_u32(3).pow_u32(60).collatz_steps(), 739);
+        assert_eq!(UBigInt::from_string("12345678901234567890123456789").unwrap().collatz_steps(), 546);
+    }
+
+    #[test]
+    fn smallest_prime_factor_below_test() {
+        assert_eq!(UBigInt::from_u32(21).smallest_prime_factor_below(100), Some(3));
+        assert_eq!(UBigInt::from_u32(21).smallest_prime_factor_below(2), None);
+        assert_eq!(UBigInt::from_u32(21).smallest_prime_factor_below(3), Some(3));
+        assert_eq!(UBigInt::from_u32(1024).smallest_prime_factor_below(2), Some(2));
+        assert_eq!(UBigInt::one().smallest_prime_factor_below(u32::MAX), None);
+        assert_eq!(UBigInt::from_u32(65521).smallest_prime_factor_below(255), None);
+
+        // 1000003 is a prime, and 1000003 > 1000^2
+        assert_eq!(UBigInt::from_u32(1000003).smallest_prime_factor_below(1000), None);
+
+        // primes above 256
+        assert_eq!(UBigInt::from_u32(257 * 263).smallest_prime_factor_below(1000), Some(257));
+        assert_eq!(UBigInt::from_u32(257 * 263).smallest_prime_factor_below(256), None);
+        assert_eq!(UBigInt::from_u64(65537 * 65537).smallest_prime_factor_below(70000), Some(65537));
+
+        let big = UBigInt::from_u32(7919).mul(&UBigInt::from_u32(2).pow_u32(127).sub_u32(1));
+        assert_eq!(big.smallest_prime_factor_below(10000), Some(7919));
+
+        for n in 2..3000u32 {
+            let smallest = (2..=n).find(|d| n % d == 0).unwrap();
+            assert_eq!(UBigInt::from_u32(n).smallest_prime_factor_below(n), Some(smallest));
+            assert_eq!(UBigInt::from_u32(n).smallest_prime_factor_below(smallest - 1), None);
+        }
+    }
+
+    #[test]
+    fn sum_of_two_squares_test() {
+        assert_eq!(UBigInt::from_u32(5).sum_of_two_squares(), Some((UBigInt::from_u32(1), UBigInt::from_u32(2))));
+        assert_eq!(UBigInt::from_u32(3).sum_of_two_squares(), None);
+        assert_eq!(UBigInt::from_u32(21).sum_of_two_squares(), None);
+        assert_eq!(UBigInt::zero().sum_of_two_squares(), Some((UBigInt::zero(), UBigInt::zero())));
+        assert_eq!(UBigInt::one().sum_of_two_squares(), Some((UBigInt::zero(), UBigInt::one())));
+        assert_eq!(UBigInt::from_u32(9).sum_of_two_squares(), Some((UBigInt::zero(), UBigInt::from_u32(3))));
+
+        let (a, b) = UBigInt::from_u32(25).sum_of_two_squares().unwrap();
+        assert_eq!(a.mul(&a).add(&b.mul(&b)), UBigInt::from_u32(25));
+
+        for n in 0..2000u32 {
+            let brute_force = (0..=n).take_while(|a| a * a <= n).any(|a| (a..=n).take_while(|b| a * a + b * b <= n).any(|b| a * a + b * b == n));
+            let result = UBigInt::from_u32(n).sum_of_two_squares();
+
+            assert_eq!(result.is_some(), brute_force, "{n}");
+
+            if let Some((a, b)) = result {
+                assert!(a.leq(&b));
+                assert_eq!(a.mul(&a).add(&b.mul(&b)), UBigInt::from_u32(n));
+            }
+        }
+
+        // 10009 and 1000000009 are primes of form 4k + 1
+        let n = UBigInt::from_u64(1_000_000_009).mul(&UBigInt::from_u32(10009)).mul(&UBigInt::from_u32(49));
+        let (a, b) = n.sum_of_two_squares().unwrap();
+        assert_eq!(a.mul(&a).add(&b.mul(&b)), n);
+    }
+
+    #[test]
+    fn ct_select_test() {
+        let mut samples = vec![UBigInt::zero(), UBigInt::one(), UBigInt::from_u64(u64::MAX)];
+        let mut seed = 0x1234_5678u32;
+
+        for len in 1..12 {
+            samples.push(rand_ubi(len, &mut seed));
+        }
+
+        for a in samples.iter() {
+            for b in samples.iter() {
+                assert_eq!(&UBigInt::ct_select(true, a, b), a);
+                assert_eq!(&UBigInt::ct_select(false, a, b), b);
+            }
+        }
+    }
+
+    #[test]
+    fn midpoint_test() {
+        let mut samples = vec![UBigInt::zero(), UBigInt::one(), UBigInt::from_u32(2), UBigInt::from_u64(u64::MAX)];
+        let mut seed = 0x8765_4321u32;
+
+        for len in 1..12 {
+            for _ in 0..3 {
+                samples.push(rand_ubi(len, &mut seed));
+            }
+        }
+
+        for a in samples.iter() {
+            for b in samples.iter() {
+                assert_eq!(a.midpoint(b), a.add(b).div_u32(2));
+                assert_eq!(a.midpoint(b), b.midpoint(a));
+            }
+        }
+
+        assert_eq!(UBigInt::from_u32(3).midpoint(&UBigInt::from_u32(6)), UBigInt::from_u32(4));
+        assert_eq!(UBigInt::from_u32(7).midpoint(&UBigInt::from_u32(7)), UBigInt::from_u32(7));
+    }
+
+    #[test]
+    fn gcd_u32_test() {
+        let mut seed = 0x2468_ace0u32;
+        let mut next = || rand_u32(&mut seed);
+
+        for len in 1..8 {
+            for _ in 0..20 {
+                // `n` and `other` share a factor `small`
+                let small = (next() >> (next() % 32)).max(1);
+                let n = UBigInt::from_raw((0..len).map(|_| next().max(1)).collect()).mul_u32(small);
+
+                for other in [small, small.wrapping_mul(6).max(1), 1, 2, u32::MAX, next().max(1)] {
+                    assert_eq!(
+                        n.gcd_u32(other),
+                        super::gcd_ubi(&n, &UBigInt::from_u32(other)).to_u32().unwrap(),
+                    );
+                }
+            }
+        }
+
+        assert_eq!(UBigInt::from_u32(12).gcd_u32(18), 6);
+        assert_eq!(UBigInt::from_u32(12).gcd_u32(0), 12);
+        assert_eq!(UBigInt::zero().gcd_u32(18), 18);
+        assert_eq!(UBigInt::from_u32(2).pow_u32(100).gcd_u32(96), 32);
+    }
+
+    #[test]
+    fn gcd_binary_test() {
+        let mut seed = 0x1357_9bdfu32;
+        let mut next = || rand_u32(&mut seed);
+        let mut random_ubi = |len: usize| UBigInt::from_raw((0..len).map(|_| next().max(1)).collect());
+
+        for a_len in [1, 2, 3, 5, 12] {
+            for b_len in [1, 2, 3, 4, 12, 30] {
+                for common_len in [1, 2, 6] {
+                    let common = random_ubi(common_len).mul(&UBigInt::exp2((a_len * b_len * 7 % 70) as u64));
+                    let a = random_ubi(a_len).mul(&common);
+                    let b = random_ubi(b_len).mul(&common);
+                    let answer = super::gcd_ubi(&a, &b);
+
+                    assert_eq!(super::gcd_binary_ubi(&a, &b), answer);
+                    assert_eq!(super::gcd_binary_ubi(&b, &a), answer);
+                    assert_eq!(super::gcd_binary_ubi(&a, &a), a);
+                }
+            }
+        }
+
+        let n = |n: u64| UBigInt::from_u64(n);
+
+        for a in 0..40 {
+            for b in 0..40 {
+                assert_eq!(super::gcd_binary_ubi(&n(a), &n(b)), super::gcd_ubi(&n(a), &n(b)));
+            }
+        }
+
+        assert_eq!(super::gcd_binary_ubi(&UBigInt::exp2(200), &UBigInt::exp2(130).mul_u32(3)), UBigInt::exp2(130));
+        assert_eq!(super::gcd_binary_ubi(&UBigInt::exp2(200), &UBigInt::zero()), UBigInt::exp2(200));
+        assert_eq!(super::gcd_binary_ubi(&UBigInt::zero(), &UBigInt::zero()), UBigInt::zero());
+    }
+
+    #[test]
+    fn factorial_mod_test() {
+        for n in 0..60 {
+            for m in [1, 2, 7, 97, 1000, u32::MAX] {
+                assert_eq!(
+                    UBigInt::factorial_mod(n, &UBigInt::from_u32(m)),
+                    UBigInt::factorial(n).rem_u32(m),
+                );
+            }
+
+            let m = UBigInt::from_raw(vec![3, 2, 1]);
+            assert_eq!(UBigInt::factorial_mod(n, &m), UBigInt::factorial(n).rem(&m));
+        }
+
+        for n in 0..2000 {
+            let n_ = UBigInt::from_u32(n);
+            assert_eq!(n_.is_prime_wilson(), n_.is_prime() && n > 1, "{n}");
+        }
+
+        assert!(UBigInt::from_u32(65521).is_prime_wilson());
+        assert!(!UBigInt::from_u32(65523).is_prime_wilson());
+    }
+
+    #[test]
+    fn pow_mod_test() {
+        let n = |n: u64| UBigInt::from_u64(n);
+
+        assert_eq!(n(4).pow_mod(&n(13), &n(497)), n(445));
+        assert_eq!(n(2).pow_mod(&n(10), &n(1000)), n(24));
+        assert_eq!(n(5).pow_mod(&n(0), &n(7)), n(1));
+        assert_eq!(n(5).pow_mod(&n(0), &n(1)), n(0));
+        assert_eq!(n(0).pow_mod(&n(0), &n(7)), n(1));
+        assert_eq!(n(0).pow_mod(&n(5), &n(7)), n(0));
+        assert_eq!(n(123456789).pow_mod(&n(1), &n(1000)), n(789));
+
+        for base in 0..20u64 {
+            for exp in 0..20u32 {
+                for m in [1, 2, 3, 10, 97, 1 << 20] {
+                    assert_eq!(
+                        n(base).pow_mod(&n(exp as u64), &n(m)),
+                        n(base).pow_u32(exp).rem(&n(m)),
+                    );
+                }
+            }
+        }
+
+        // Fermat's little theorem: a^(p - 1) = 1 (mod p)
+        let primes = [
+            UBigInt::from_u64(1_000_000_007),
+            UBigInt::from_u32(2).pow_u32(127).sub_u32(1),
+            UBigInt::from_u32(2).pow_u32(521).sub_u32(1),
+        ];
+
+        for p in primes.iter() {
+            for a in [2, 3, 12345, u32::MAX] {
+                assert_eq!(UBigInt::from_u32(a).pow_mod(&p.sub_u32(1), p), UBigInt::one());
+            }
+        }
+
+        // 2^340 = 1 (mod 341), though 341 = 11 * 31 is not a prime
+        assert_eq!(n(2).pow_mod(&n(340), &n(341)), n(1));
+        assert_eq!(n(3).pow_mod(&n(340), &n(341)), n(56));
+
+        // RSA with p = 61, q = 53, e = 17, d = 413
+        let m = n(3233);
+        let cipher = n(65).pow_mod(&n(17), &m);
+        assert_eq!(cipher, n(2790));
+        assert_eq!(cipher.pow_mod(&n(413), &m), n(65));
+
+        // compare with the Barrett version
+        let modulus = UBigInt::from_raw(vec![0x1234_5678, 0x9abc_def0, 0x1357_9bdf, 7]);
+        let ctx = BarrettCtx::new(&modulus);
+        let base = UBigInt::from_raw(vec![3, 1, 4, 1, 5, 9, 2, 6]);
+
+        for exp in [0, 1, 2, 65537, u32::MAX] {
+            let exp = UBigInt::from_u32(exp).mul(&UBigInt::from_raw(vec![5, 5, 5]));
+            assert_eq!(base.pow_mod(&exp, &modulus), base.pow_mod_ctx(&exp, &ctx));
+        }
+    }
+
+    #[test]
+    fn miller_rabin_test() {
+        // trial division and Miller-Rabin must agree
+        for n in 0..5000u32 {
+            let n = UBigInt::from_u32(n);
+            assert_eq!(n.is_prime_mr(0), n.is_prime(), "{n}");
+        }
+
+        let primes = [
+            "4294967311",
+            "18446744073709551557",  // largest prime below 2^64
+            "170141183460469231731687303715884105727",  // 2^127 - 1
+            "3317044064679887385961783",  // a prime below the deterministic limit
+            "359334085968622831041960188598043661065388726959079837",  // the 10th Bell number prime
+        ];
+        let composites = [
+            "18446744073709551557000",
+            "3317044064679887385961981",  // strong pseudoprime to the bases 2..=41, so only the pseudo-random witnesses can catch it
+            "3825123056546413051",  // strong pseudoprime to the bases 2..=31
+            "318665857834031151167461",  // strong pseudoprime to the bases 2..=37
+            "340282366920938463463374607431768211457",  // 2^128 + 1
+        ];
+
+        for p in primes.iter() {
+            let p = UBigInt::from_string(p).unwrap();
+            assert!(p.is_prime_mr(8), "{p}");
+            assert!(p.is_prime(), "{p}");
+        }
+
+        for c in composites.iter() {
+            let c = UBigInt::from_string(c).unwrap();
+            assert!(!c.is_prime_mr(8), "{c}");
+            assert!(!c.is_prime(), "{c}");
+        }
+
+        // Mersenne primes and non-primes
+        for (exp, is_prime) in [(61, true), (67, false), (89, true), (107, true), (127, true), (257, false), (521, true)] {
+            assert_eq!(UBigInt::from_u32(2).pow_u32(exp).sub_u32(1).is_prime(), is_prime, "2^{exp} - 1");
+        }
+
+        // product of two big primes
+        let p = UBigInt::from_string("170141183460469231731687303715884105727").unwrap();
+        let q = UBigInt::from_string("18446744073709551557").unwrap();
+        assert!(!p.mul(&q).is_prime());
+    }
+
+    #[test]
+    fn mod_inv_test() {
+        let n = |n: u64| UBigInt::from_u64(n);
+
+        assert_eq!(n(3).mod_inv(&n(11)), Some(n(4)));
+        assert_eq!(n(10).mod_inv(&n(17)), Some(n(12)));
+        assert_eq!(n(14).mod_inv(&n(11)), Some(n(4)));
+        assert_eq!(n(1).mod_inv(&n(1)), Some(n(0)));
+        assert_eq!(n(6).mod_inv(&n(9)), None);
+        assert_eq!(n(0).mod_inv(&n(9)), None);
+        assert_eq!(n(9).mod_inv(&n(9)), None);
+
+        for m in 2..60u64 {
+            for a in 0..m {
+                let inv = n(a).mod_inv(&n(m));
+
+                if super::gcd_ubi(&n(a), &n(m)).is_one() {
+                    let inv = inv.unwrap();
+                    assert!(inv.lt(&n(m)));
+                    assert_eq!(n(a).mul(&inv).rem(&n(m)), n(1));
+                }
+
+                else {
+                    assert_eq!(inv, None);
+                }
+            }
+        }
+
+        // Fermat: a^-1 = a^(p - 2) (mod p)
+        let p = UBigInt::from_u32(2).pow_u32(127).sub_u32(1);
+        let a = UBigInt::from_string("123456789012345678901234567890").unwrap();
+        assert_eq!(a.mod_inv(&p), Some(a.pow_mod(&p.sub_u32(2), &p)));
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn factorial_parallel_test() {
+        for n in [0, 1, 2, 20, 128, 1023, 1024, 1025, 3000, 10007] {
+            assert_eq!(UBigInt::factorial_parallel(n), UBigInt::factorial(n), "{n}");
+        }
+    }
+
+    #[test]
+    fn lcm_test() {
+        let n = |n: u64| UBigInt::from_u64(n);
+
+        assert_eq!(super::lcm_ubi(&n(12), &n(18)), n(36));
+        assert_eq!(super::lcm_ubi(&n(18), &n(12)), n(36));
+        assert_eq!(super::lcm_ubi(&n(7), &n(13)), n(91));
+        assert_eq!(super::lcm_ubi(&n(5), &n(5)), n(5));
+        assert_eq!(super::lcm_ubi(&n(1), &n(5)), n(5));
+        assert_eq!(super::lcm_ubi(&n(0), &n(5)), n(0));
+        assert_eq!(super::lcm_ubi(&n(5), &n(0)), n(0));
+        assert_eq!(super::lcm_ubi(&n(0), &n(0)), n(0));
+
+        for a in 1..50u64 {
+            for b in 1..50u64 {
+                let lcm = super::lcm_ubi(&n(a), &n(b));
+                assert_eq!(lcm.mul(&super::gcd_ubi(&n(a), &n(b))), n(a * b));
+            }
+        }
+
+        // lcm(1, 2, ..., 30)
+        let mut lcm = UBigInt::one();
+
+        for i in 1..31 {
+            lcm = super::lcm_ubi(&lcm, &n(i));
+        }
+
+        assert_eq!(lcm, n(2329089562800));
+    }
 }
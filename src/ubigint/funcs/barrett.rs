@@ -0,0 +1,145 @@
+use crate::UBigInt;
+
+/// Precomputed values for Barrett reduction.\
+/// If you compute many `x % modulus` with the same `modulus`, this is cheaper than calling `rem` every time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BarrettCtx {
+    modulus: UBigInt,
+
+    // truncate(2^(64k) / modulus)
+    mu: UBigInt,
+
+    // number of limbs of `modulus`
+    k: usize,
+}
+
+impl BarrettCtx {
+
+    /// It panics when `modulus` is 0.
+    pub fn new(modulus: &UBigInt) -> Self {
+        if modulus.is_zero() {
+            panic!("Attempt to divide by zero: BarrettCtx::new(0)");
+        }
+
+        let k = modulus.len();
+        let mu = UBigInt::one().shift_left(2 * k).div(modulus);
+
+        BarrettCtx { modulus: modulus.clone(), mu, k }
+    }
+
+    pub fn modulus(&self) -> &UBigInt {
+        &self.modulus
+    }
+
+    /// x % modulus
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn reduce(&self, x: &UBigInt) -> UBigInt {
+        // it only works when x < 2^(64k)
+        if x.len() > 2 * self.k {
+            return x.rem(&self.modulus);
+        }
+
+        let q = x.shift_right(self.k - 1).mul(&self.mu).shift_right(self.k + 1);
+        let mut result = x.sub(&q.mul(&self.modulus));
+
+        // `q` is at most 2 less than the actual quotient
+        while result.geq(&self.modulus) {
+            result.sub_mut(&self.modulus);
+        }
+
+        result
+    }
+}
+
+impl UBigInt {
+
+    /// self^exp % ctx.modulus()\
+    /// It reuses the precomputed values in `ctx` for every multiplication.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn pow_mod_ctx(&self, exp: &UBigInt, ctx: &BarrettCtx) -> Self {
+        let base = ctx.reduce(self);
+        let mut result = ctx.reduce(&UBigInt::one());
+
+        // from the most significant bit
+        for limb in exp.0.iter().rev() {
+            for i in (0..32).rev() {
+                result = ctx.reduce(&result.mul(&result));
+
+                if limb & (1 << i) != 0 {
+                    result = ctx.reduce(&result.mul(&base));
+                }
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UBigInt;
+    use crate::utils::rand_ubi;
+    use super::BarrettCtx;
+
+    #[test]
+    fn barrett_test() {
+        let moduli = [
+            UBigInt::one(),
+            UBigInt::from_u32(7),
+            UBigInt::from_u32(u32::MAX),
+            UBigInt::from_u64(1_000_000_007 * 998_244_353),
+            UBigInt::from_u32(2).pow_u32(127).sub_u32(1),
+            UBigInt::from_raw(vec![1, 2, 3, 4, 5, 6, 7]),
+        ];
+        let bases = [
+            UBigInt::zero(),
+            UBigInt::from_u32(2),
+            UBigInt::from_u32(12345),
+            UBigInt::from_raw(vec![9, 8, 7, 6, 5, 4, 3, 2, 1]),
+        ];
+
+        for modulus in moduli.iter() {
+            let ctx = BarrettCtx::new(modulus);
+
+            for base in bases.iter() {
+                for exp in [0, 1, 2, 3, 10, 65, 100] {
+                    assert_eq!(
+                        base.pow_mod_ctx(&UBigInt::from_u32(exp), &ctx),
+                        base.pow_u32(exp).rem(modulus),
+                    );
+                }
+            }
+
+            // numbers larger than modulus^2
+            assert_eq!(ctx.reduce(&modulus.pow_u32(3).add_u32(5)), UBigInt::from_u32(5).rem(modulus));
+        }
+
+        // Fermat's little theorem
+        let p = UBigInt::from_u32(2).pow_u32(127).sub_u32(1);
+        let ctx = BarrettCtx::new(&p);
+
+        for a in 2..50 {
+            assert_eq!(UBigInt::from_u32(a).pow_mod_ctx(&p.sub_u32(1), &ctx), UBigInt::one());
+        }
+    }
+
+    #[test]
+    fn reduce_test() {
+        for modulus in [UBigInt::from_u32(3), UBigInt::from_u32(u32::MAX), UBigInt::from_raw(vec![7, 0, 1]), UBigInt::from_raw(vec![u32::MAX; 5])] {
+            let ctx = BarrettCtx::new(&modulus);
+            let mut seed = 0x1357_9bdfu32;
+
+            // from smaller than `modulus` to larger than `modulus^2`
+            for len in 1..(4 * modulus.len() + 2) {
+                let x = rand_ubi(len, &mut seed);
+
+                assert_eq!(ctx.reduce(&x), x.rem(&modulus));
+                assert_eq!(ctx.reduce(&x.mul(&modulus)), UBigInt::zero());
+            }
+
+            assert_eq!(ctx.reduce(&UBigInt::zero()), UBigInt::zero());
+            assert_eq!(ctx.reduce(&modulus), UBigInt::zero());
+            assert_eq!(ctx.reduce(&modulus.sub_u32(1)), modulus.sub_u32(1));
+        }
+    }
+}
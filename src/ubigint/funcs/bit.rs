@@ -0,0 +1,220 @@
+use crate::UBigInt;
+use crate::utils::remove_suffix_0;
+use super::log2_u32;
+
+impl UBigInt {
+
+    /// self & !mask\
+    /// It clears the bits of `self` that are set in `mask`.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn and_not(&self, mask: &UBigInt) -> Self {
+        let mut result = self.clone();
+        result.and_not_mut(mask);
+
+        result
+    }
+
+    /// self &= !mask
+    pub fn and_not_mut(&mut self, mask: &UBigInt) {
+        for (n, m) in self.0.iter_mut().zip(mask.0.iter()) {
+            *n &= !m;
+        }
+
+        remove_suffix_0(&mut self.0);
+
+        #[cfg(test)] assert!(self.is_valid());
+    }
+
+    /// It returns the `index`th bit of `self`: the least significant bit is the 0th bit.\
+    /// It returns false if `index` is bigger than the most significant bit.
+    pub fn get_bit(&self, index: u64) -> bool {
+        match self.0.get((index / 32) as usize) {
+            Some(n) => n & (1 << (index % 32)) != 0,
+            None => false,
+        }
+    }
+
+    /// It sets the `index`th bit of `self` to `value`: the least significant bit is the 0th bit.
+    pub fn set_bit(&mut self, index: u64, value: bool) {
+        let limb = (index / 32) as usize;
+
+        if limb >= self.len() {
+            if !value {
+                return;
+            }
+
+            self.0.resize(limb + 1, 0);
+        }
+
+        if value {
+            self.0[limb] |= 1 << (index % 32);
+        }
+
+        else {
+            self.0[limb] &= !(1 << (index % 32));
+            remove_suffix_0(&mut self.0);
+        }
+
+        #[cfg(test)] assert!(self.is_valid());
+    }
+
+    /// number of the low-order zero bits: 12 (0b1100) -> 2\
+    /// It panics when `self` is 0.
+    pub fn trailing_zeros(&self) -> u64 {
+        if self.is_zero() {
+            panic!("Attempt to count the trailing zeros of 0");
+        }
+
+        // Safety: `self` is not 0, so there's at least one non-zero limb
+        let zero_limbs = self.0.iter().position(|n| *n != 0).unwrap();
+
+        zero_limbs as u64 * 32 + self.0[zero_limbs].trailing_zeros() as u64
+    }
+
+    /// number of 1s in the binary representation of `self`
+    pub fn count_ones(&self) -> u64 {
+        self.0.iter().map(|n| n.count_ones() as u64).sum()
+    }
+
+    /// number of significant bits: `log2(self) + 1`. It returns 0 when `self` is 0.
+    pub fn bits(&self) -> u64 {
+        if self.is_zero() {
+            return 0;
+        }
+
+        (self.len() as u64 - 1) * 32 + log2_u32(self.0[self.len() - 1]) as u64 + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UBigInt;
+    use crate::utils::rand_u64;
+
+    #[test]
+    fn and_not_test() {
+        let mut seed = 0x1357_2468u64;
+        let mut samples = vec![0, 1, u32::MAX as u64, 1 << 32, u64::MAX];
+
+        for _ in 0..20 {
+            let r = rand_u64(&mut seed);
+            samples.push(r >> (r % 64));
+        }
+
+        for a in samples.iter() {
+            for b in samples.iter() {
+                assert_eq!(UBigInt::from_u64(*a).and_not(&UBigInt::from_u64(*b)), UBigInt::from_u64(a & !b));
+            }
+        }
+
+        let big = UBigInt::from_raw(vec![u32::MAX; 5]);
+
+        // the mask is shorter than `self`
+        assert_eq!(big.and_not(&UBigInt::from_u32(0xff)), UBigInt::from_raw(vec![0xffff_ff00, u32::MAX, u32::MAX, u32::MAX, u32::MAX]));
+
+        // the mask is longer than `self`
+        assert_eq!(UBigInt::from_u32(0xf0f0).and_not(&big), UBigInt::zero());
+        assert_eq!(UBigInt::from_u32(0xf0f0).and_not(&UBigInt::from_raw(vec![0xff, 1, 2])), UBigInt::from_u32(0xf000));
+
+        // clearing the most significant limbs
+        assert_eq!(big.and_not(&UBigInt::from_raw(vec![0, 0, 0, u32::MAX, u32::MAX])), UBigInt::from_raw(vec![u32::MAX; 3]));
+        assert_eq!(big.and_not(&big), UBigInt::zero());
+        assert_eq!(big.and_not(&UBigInt::zero()), big);
+    }
+
+    #[test]
+    fn count_ones_bits_test() {
+        for n in 0..300 {
+            let p = UBigInt::pow2(n);
+
+            assert_eq!(p.bits(), n as u64 + 1);
+            assert_eq!(p.count_ones(), 1);
+            assert_eq!(p.sub_u32(1).count_ones(), n as u64);
+            assert_eq!(p.sub_u32(1).bits(), n as u64);
+        }
+
+        assert_eq!(UBigInt::pow2(100).sub_u32(1).count_ones(), 100);
+        assert_eq!(UBigInt::zero().bits(), 0);
+        assert_eq!(UBigInt::zero().count_ones(), 0);
+
+        let mut seed = 0x2468_1357u64;
+
+        for _ in 0..64 {
+            let r = rand_u64(&mut seed);
+            let n = r >> (r % 64);
+
+            assert_eq!(UBigInt::from_u64(n).count_ones(), n.count_ones() as u64);
+            assert_eq!(UBigInt::from_u64(n).bits(), 64 - n.leading_zeros() as u64);
+        }
+    }
+
+    #[test]
+    fn trailing_zeros_test() {
+        for n in 0..300 {
+            let p = UBigInt::pow2(n);
+
+            assert_eq!(p.trailing_zeros(), n as u64);
+            assert_eq!(p.mul_u32(3).trailing_zeros(), n as u64);
+            assert_eq!(p.add_u32(1).trailing_zeros(), if n == 0 { 1 } else { 0 });
+        }
+
+        for n in [1, 3, 5, 7, 12345, u32::MAX] {
+            assert_eq!(UBigInt::from_u32(n).trailing_zeros(), 0);
+        }
+
+        let mut seed = 0x1234_5678u64;
+
+        for _ in 0..64 {
+            let r = rand_u64(&mut seed);
+            let n = (r >> (r % 64)).max(1);
+
+            assert_eq!(UBigInt::from_u64(n).trailing_zeros(), n.trailing_zeros() as u64);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn trailing_zeros_panic_test() {
+        let _ = UBigInt::zero().trailing_zeros();
+    }
+
+    #[test]
+    fn get_set_bit_test() {
+        let mut n = UBigInt::zero();
+
+        for index in [0, 31, 32, 63, 64, 100] {
+            assert!(!n.get_bit(index));
+
+            n.set_bit(index, true);
+            assert!(n.get_bit(index));
+            assert_eq!(n.bits(), index + 1);
+        }
+
+        assert_eq!(
+            n,
+            [0, 31, 32, 63, 64, 100].iter().fold(UBigInt::zero(), |acc, i| acc.add(&UBigInt::pow2(*i))),
+        );
+
+        // setting a bit that's already set
+        n.set_bit(64, true);
+        assert_eq!(n.count_ones(), 6);
+
+        for index in [100, 64, 63, 32, 31, 0] {
+            n.set_bit(index, false);
+            assert!(!n.get_bit(index));
+            assert!(n.is_valid());
+        }
+
+        assert_eq!(n, UBigInt::zero());
+
+        // clearing a bit that's not in `self`
+        n.set_bit(1000, false);
+        assert_eq!(n, UBigInt::zero());
+
+        let n = UBigInt::from_u64(0b1011 << 30);
+
+        for index in 0..128 {
+            assert_eq!(n.get_bit(index), [30, 31, 33].contains(&index));
+        }
+    }
+}
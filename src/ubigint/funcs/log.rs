@@ -12,28 +12,52 @@ impl UBigInt {
     /// It returns `truncate(log2(self) * 4294967296)`. It returns 0 when `self` is 0.
     #[must_use = "method returns a new number and does not mutate the original value"]
     pub fn log2_accurate(&self) -> Self {
-        let mut result = 0;
-        let mut self_clone = if self.len() > 8 {
-            result += (self.len() - 8) as u64 * 32;
-            self.shift_right(self.len() - 8)
+        self.log2_accurate_bits(32)
+    }
+
+    /// It returns `truncate(log2(self) * 2^frac_bits)`. It returns 0 when `self` is 0.\
+    /// `self.log2_accurate_bits(32)` is `self.log2_accurate()`, and `self.log2_accurate_bits(0)` is `self.log2()`.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn log2_accurate_bits(&self, frac_bits: u32) -> Self {
+        // the more fractional bits it wants, the more limbs it keeps while squaring
+        let keep = 6.max(frac_bits as usize / 16 + 4);
+
+        let mut result = UBigInt::zero();
+        let mut self_clone = if self.len() > keep + 2 {
+            result = UBigInt::from_u64((self.len() - keep - 2) as u64 * 32);
+            self.shift_right(self.len() - keep - 2)
         } else {
             self.clone()
         };
 
-        for _ in 0..16 {
+        // log2(x^4) = 4 * log2(x)
+        for _ in 0..(frac_bits / 2) {
             self_clone = self_clone.mul(&self_clone);
             self_clone = self_clone.mul(&self_clone);
-            result *= 4;
+            result.mul_u32_mut(4);
 
-            if self_clone.len() > 6 {
-                result += (self_clone.len() - 6) as u64 * 32;
-                self_clone.shift_right_mut(self_clone.len() - 6);
+            if self_clone.len() > keep {
+                result.add_u32_mut((self_clone.len() - keep) as u32 * 32);
+                self_clone.shift_right_mut(self_clone.len() - keep);
             }
 
         }
 
-        result += (self_clone.len() as u64 - 1) * 32 + log2_u32(self_clone.0[self_clone.len() - 1]) as u64;
-        UBigInt::from_u64(result)
+        if frac_bits % 2 == 1 {
+            self_clone = self_clone.mul(&self_clone);
+            result.mul_u32_mut(2);
+
+            if self_clone.len() > keep {
+                result.add_u32_mut((self_clone.len() - keep) as u32 * 32);
+                self_clone.shift_right_mut(self_clone.len() - keep);
+            }
+        }
+
+        result.add_mut(&UBigInt::from_u64((self_clone.len() as u64 - 1) * 32 + log2_u32(self_clone.0[self_clone.len() - 1]) as u64));
+
+        #[cfg(test)] assert!(result.is_valid());
+
+        result
     }
 }
 
@@ -91,4 +115,56 @@ mod tests {
             "13.273",
         );
     }
+
+    #[test]
+    fn log2_accurate_bits_test() {
+        let samples = [
+            UBigInt::one(),
+            UBigInt::from_u32(2),
+            UBigInt::from_u32(3),
+            UBigInt::from_u32(10),
+            UBigInt::from_u32(9900),
+            UBigInt::from_u64(u64::MAX),
+            UBigInt::from_string("123456789012345678901234567890123456789012345678901234567890").unwrap(),
+            UBigInt::from_u32(7).pow_u32(200),
+        ];
+
+        for n in samples.iter() {
+            assert_eq!(n.log2_accurate_bits(32), n.log2_accurate());
+            assert_eq!(n.log2_accurate_bits(0), n.log2());
+
+            // truncate(2x) is 2 * truncate(x) or 2 * truncate(x) + 1
+            for frac_bits in 0..80 {
+                let curr = n.log2_accurate_bits(frac_bits);
+                let next = n.log2_accurate_bits(frac_bits + 1);
+
+                assert!(next.geq(&curr.mul_u32(2)), "{n}, {frac_bits}");
+                assert!(next.leq(&curr.mul_u32(2).add_u32(1)), "{n}, {frac_bits}");
+            }
+        }
+
+        // exact for powers of 2
+        for exp in [0, 1, 31, 32, 33, 100, 1000] {
+            for frac_bits in [0, 1, 24, 64, 100] {
+                assert_eq!(
+                    UBigInt::from_u32(2).pow_u32(exp).log2_accurate_bits(frac_bits),
+                    UBigInt::from_u32(exp).mul(&UBigInt::from_u32(2).pow_u32(frac_bits)),
+                );
+            }
+        }
+
+        // monotonicity
+        for frac_bits in [8, 24, 48] {
+            let mut prev = UBigInt::from_u32(1).log2_accurate_bits(frac_bits);
+
+            for n in 2..2000 {
+                let curr = UBigInt::from_u32(n).log2_accurate_bits(frac_bits);
+                assert!(curr.geq(&prev), "{n}, {frac_bits}");
+                prev = curr;
+            }
+        }
+
+        // log2(3) = 1.10010101110000000001100111111...
+        assert_eq!(UBigInt::from_u32(3).log2_accurate_bits(12).to_u32().unwrap(), 0b1_100101011100);
+    }
 }
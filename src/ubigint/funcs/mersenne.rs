@@ -0,0 +1,150 @@
+use crate::UBigInt;
+use crate::utils::remove_suffix_0;
+
+impl UBigInt {
+
+    /// 2^p - 1
+    pub fn mersenne(p: u32) -> UBigInt {
+        let mut result = vec![u32::MAX; p as usize / 32];
+
+        let bit = p % 32;
+
+        if bit != 0 {
+            result.push((1 << bit) - 1);
+        }
+
+        if result.is_empty() {
+            return UBigInt::zero();
+        }
+
+        UBigInt::from_raw(result)
+    }
+
+    /// It tells whether `2^p - 1` is a prime number, using the Lucas-Lehmer test.
+    pub fn is_mersenne_prime(p: u32) -> bool {
+        if p == 2 {
+            return true;
+        }
+
+        // if `p` is a composite number, so is `2^p - 1`
+        if !UBigInt::from_u32(p).is_prime() {
+            return false;
+        }
+
+        let m = UBigInt::mersenne(p);
+        let mut s = UBigInt::from_u32(4);
+
+        for _ in 0..(p - 2) {
+            s = s.mul(&s);
+
+            if s.lt_u32(2) {
+                s.add_mut(&m);
+            }
+
+            s.sub_u32_mut(2);
+            s = rem_mersenne(&s, p, &m);
+        }
+
+        s.is_zero()
+    }
+}
+
+// x % (2^p - 1), where `m` is 2^p - 1
+// x = high * 2^p + low = high + low (mod 2^p - 1), so it doesn't need any division
+fn rem_mersenne(x: &UBigInt, p: u32, m: &UBigInt) -> UBigInt {
+    let mut result = x.clone();
+
+    while result.bits() > p as u64 {
+        let (low, high) = split_at_bit(&result, p);
+        result = low.add(&high);
+    }
+
+    if result.eq(m) {
+        UBigInt::zero()
+    }
+
+    else {
+        result
+    }
+}
+
+// (x % 2^p, x / 2^p)
+fn split_at_bit(x: &UBigInt, p: u32) -> (UBigInt, UBigInt) {
+    let limb = p as usize / 32;
+    let bit = p % 32;
+
+    if limb >= x.len() {
+        return (x.clone(), UBigInt::zero());
+    }
+
+    let mut low = x.0[..limb].to_vec();
+
+    if bit != 0 {
+        low.push(x.0[limb] & ((1 << bit) - 1));
+    }
+
+    if low.is_empty() {
+        low.push(0);
+    }
+
+    let mut high = Vec::with_capacity(x.len() - limb);
+
+    for i in limb..x.len() {
+        let mut curr = x.0[i] >> bit;
+
+        if bit != 0 && i + 1 < x.len() {
+            curr |= x.0[i + 1] << (32 - bit);
+        }
+
+        high.push(curr);
+    }
+
+    remove_suffix_0(&mut low);
+    remove_suffix_0(&mut high);
+
+    (UBigInt::from_raw(low), UBigInt::from_raw(high))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UBigInt;
+    use super::rem_mersenne;
+
+    #[test]
+    fn mersenne_test() {
+        for p in 0..100 {
+            assert_eq!(UBigInt::mersenne(p), UBigInt::from_u32(2).pow_u32(p).sub_u32(1));
+        }
+
+        for p in [2, 3, 5, 7, 13] {
+            assert!(UBigInt::is_mersenne_prime(p));
+        }
+
+        // 2^11 - 1 = 23 * 89
+        assert!(!UBigInt::is_mersenne_prime(11));
+
+        let exponents = [2, 3, 5, 7, 13, 17, 19, 31, 61, 89, 107, 127, 521, 607, 1279];
+
+        for p in 0..1300 {
+            assert_eq!(UBigInt::is_mersenne_prime(p), exponents.contains(&p), "p = {p}");
+        }
+
+        for p in 2..64 {
+            assert_eq!(UBigInt::is_mersenne_prime(p), UBigInt::mersenne(p).is_prime());
+        }
+
+        for p in [1, 5, 31, 32, 33, 64, 127] {
+            let m = UBigInt::mersenne(p);
+
+            for x in [
+                UBigInt::zero(),
+                m.clone(),
+                m.add_u32(1),
+                m.mul(&m),
+                UBigInt::from_raw(vec![0x9abc_def0, 0x1234_5678, 0xffff_ffff, 7, 8, 9]),
+            ] {
+                assert_eq!(rem_mersenne(&x, p, &m), x.rem(&m));
+            }
+        }
+    }
+}
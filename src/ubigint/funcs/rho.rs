@@ -0,0 +1,179 @@
+use crate::{BarrettCtx, UBigInt, gcd_ubi};
+use super::SMALL_PRIMES;
+
+impl UBigInt {
+
+    /// It returns the prime factors of `self`, sorted in ascending order. 12 -> [2, 2, 3]\
+    /// It uses Pollard's rho algorithm (with Brent's cycle detection), which is much faster than `prime_factorial` when `self` has big prime factors.
+    /// It uses `is_prime` to decide whether a factor is prime, so the result may be wrong for numbers bigger than 3317044064679887385961981 with a very small chance.
+    /// Like `prime_factorial`, it returns `[self]` when `self` is 0 or 1.
+    pub fn factorize_rho(&self) -> Vec<UBigInt> {
+        if self.lt_u32(2) {
+            return vec![self.clone()];
+        }
+
+        let mut result = vec![];
+        let mut rest = self.clone();
+
+        // rho is not good at finding small factors
+        for p in SMALL_PRIMES.iter() {
+            while rest.rem_u32(*p).is_zero() {
+                rest.div_u32_mut(*p);
+                result.push(UBigInt::from_u32(*p));
+            }
+        }
+
+        let mut stack = vec![rest];
+
+        while let Some(n) = stack.pop() {
+            if n.is_one() {
+                continue;
+            }
+
+            if n.is_prime() {
+                result.push(n);
+                continue;
+            }
+
+            let d = find_factor(&n);
+            stack.push(n.div(&d));
+            stack.push(d);
+        }
+
+        result.sort_by(|a, b| a.comp(b));
+
+        #[cfg(test)] {
+            let mut product = UBigInt::one();
+
+            for p in result.iter() {
+                product.mul_mut(p);
+            }
+
+            assert_eq!(&product, self);
+        }
+
+        result
+    }
+}
+
+// `n` must be an odd composite number
+fn find_factor(n: &UBigInt) -> UBigInt {
+    let ctx = BarrettCtx::new(n);
+
+    // if a `c` fails, another one would work
+    for c in 1.. {
+        if let Some(d) = rho_brent(n, &ctx, c) {
+            return d;
+        }
+    }
+
+    unreachable!()
+}
+
+// it returns a non-trivial factor of `n`, or `None` if it fails
+fn rho_brent(n: &UBigInt, ctx: &BarrettCtx, c: u32) -> Option<UBigInt> {
+    // x' = x^2 + c (mod n)
+    let f = |x: &UBigInt| ctx.reduce(&x.mul(x).add_u32(c));
+
+    // it multiplies `m` differences before calculating a gcd
+    let m = 128;
+
+    let mut y = UBigInt::from_u32(2);
+    let mut x = y.clone();
+    let mut ys = y.clone();
+    let mut q = UBigInt::one();
+    let mut g = UBigInt::one();
+    let mut r = 1;
+
+    while g.is_one() {
+        x = y.clone();
+
+        for _ in 0..r {
+            y = f(&y);
+        }
+
+        let mut k = 0;
+
+        while k < r && g.is_one() {
+            ys = y.clone();
+
+            for _ in 0..m.min(r - k) {
+                y = f(&y);
+                q = ctx.reduce(&q.mul(&abs_diff(&x, &y)));
+            }
+
+            g = gcd_ubi(&q, n);
+            k += m;
+        }
+
+        r *= 2;
+    }
+
+    // the batched product hit 0 (mod n), so it goes back and checks the differences one by one
+    if g.eq(n) {
+        loop {
+            ys = f(&ys);
+            g = gcd_ubi(&abs_diff(&x, &ys), n);
+
+            if !g.is_one() {
+                break;
+            }
+        }
+    }
+
+    if g.eq(n) {
+        None
+    }
+
+    else {
+        Some(g)
+    }
+}
+
+fn abs_diff(a: &UBigInt, b: &UBigInt) -> UBigInt {
+    if a.geq(b) { a.sub(b) } else { b.sub(a) }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UBigInt;
+    use crate::utils::rand_u32;
+
+    #[test]
+    fn factorize_rho_test() {
+        for n in 0..3000 {
+            let n = UBigInt::from_u32(n);
+            assert_eq!(n.factorize_rho(), n.prime_factorial());
+        }
+
+        let mut seed = 17u32;
+
+        for _ in 0..32 {
+            let n = UBigInt::from_u32(rand_u32(&mut seed));
+            assert_eq!(n.factorize_rho(), n.prime_factorial());
+        }
+
+        // two 30-bit primes
+        let p = UBigInt::from_u32(1073741783);
+        let q = UBigInt::from_u32(1073741789);
+        assert_eq!(p.mul(&q).factorize_rho(), vec![p.clone(), q.clone()]);
+        assert_eq!(p.mul(&p).mul(&q).factorize_rho(), vec![p.clone(), p.clone(), q.clone()]);
+
+        // 2^64 + 1 = 274177 * 67280421310721
+        assert_eq!(
+            UBigInt::from_u128((1 << 64) + 1).factorize_rho(),
+            vec![UBigInt::from_u32(274177), UBigInt::from_u64(67280421310721)],
+        );
+
+        // 2^96 - 1
+        assert_eq!(
+            UBigInt::pow2(96).sub_u32(1).factorize_rho(),
+            [3, 3, 5, 7, 13, 17, 97, 193, 241, 257, 673, 65537, 22253377].map(UBigInt::from_u32).to_vec(),
+        );
+
+        // a big prime
+        let m61 = UBigInt::pow2(61).sub_u32(1);
+        assert_eq!(m61.factorize_rho(), vec![m61.clone()]);
+        assert_eq!(m61.mul(&p).factorize_rho(), vec![p, m61]);
+    }
+}
@@ -0,0 +1,217 @@
+use crate::UBigInt;
+
+impl UBigInt {
+
+    /// It returns all the primes less than `limit`, in ascending order.\
+    /// It uses a bit-packed sieve of Eratosthenes, which takes `limit / 16` bytes of memory.
+    pub fn primes_below(limit: u32) -> Vec<UBigInt> {
+        sieve(limit).into_iter().map(UBigInt::from_u32).collect()
+    }
+
+    /// It returns the `n`th prime number. It's 1-indexed: 1 -> 2, 2 -> 3, 6 -> 13\
+    /// It sieves up to 2^26 at once, and sieves the numbers after that in segments of the same size.
+    /// It counts the primes in the bit sets without collecting them, so it takes about 4 MiB of memory.
+    /// It panics when `n` is 0.
+    pub fn nth_prime(n: u64) -> UBigInt {
+        nth_prime_worker(n, NTH_PRIME_SIEVE_LIMIT)
+    }
+}
+
+// the bit set for this number takes 4 MiB of memory, so does each segment after that
+const NTH_PRIME_SIEVE_LIMIT: u32 = 1 << 26;
+
+fn nth_prime_worker(n: u64, sieve_limit: u32) -> UBigInt {
+    if n == 0 {
+        panic!("Attempt to get the 0th prime number: `nth_prime` is 1-indexed");
+    }
+
+    // p_n < n * (ln(n) + ln(ln(n))) when n >= 6
+    let upper_bound = if n < 6 {
+        14
+    } else {
+        let ln = (n as f64).ln();
+        (n as f64 * (ln + ln.ln())) as u64 + 1
+    };
+
+    let mut remaining = n;
+
+    // it counts the primes in the bit set, without collecting them
+    for p in sieve_iter(upper_bound.min(sieve_limit as u64) as u32) {
+        remaining -= 1;
+
+        if remaining == 0 {
+            return UBigInt::from_u32(p);
+        }
+    }
+
+    // primes less than sqrt(upper_bound) are enough to sieve the segments
+    let base_primes = sieve((upper_bound.isqrt() + 1).min(u32::MAX as u64) as u32);
+    let mut segment_start = sieve_limit as u64;
+
+    loop {
+        let segment_end = segment_start + sieve_limit as u64;
+
+        for p in segmented_sieve(segment_start, segment_end, &base_primes) {
+            remaining -= 1;
+
+            if remaining == 0 {
+                return UBigInt::from_u64(p);
+            }
+        }
+
+        segment_start = segment_end;
+    }
+}
+
+// primes in `start..end`
+// `start` has to be greater than 2, and `base_primes` has to contain all the primes less than or equal to `sqrt(end)`
+fn segmented_sieve(start: u64, end: u64, base_primes: &[u32]) -> impl Iterator<Item = u64> {
+    // the `i`th bit is set if `first_odd + 2i` is a composite number
+    let first_odd = start | 1;
+    let odd_count = if end <= first_odd { 0 } else { (end - first_odd).div_ceil(2) as usize };
+    let mut composites = vec![0u64; odd_count.div_ceil(64)];
+
+    for p in base_primes.iter().skip(1) {
+        let p = *p as u64;
+
+        if p * p >= end {
+            break;
+        }
+
+        // the first odd multiple of `p` that's in the segment and is not `p` itself
+        let mut multiple = (p * p).max(first_odd.div_ceil(p) * p);
+
+        if multiple & 1 == 0 {
+            multiple += p;
+        }
+
+        let mut j = ((multiple - first_odd) / 2) as usize;
+
+        while j < odd_count {
+            composites[j / 64] |= 1 << (j % 64);
+            j += p as usize;
+        }
+    }
+
+    (0..odd_count).filter(
+        move |i| composites[i / 64] & (1 << (i % 64)) == 0
+    ).map(
+        move |i| first_odd + 2 * i as u64
+    )
+}
+
+/// primes less than `limit`
+pub(crate) fn sieve(limit: u32) -> Vec<u32> {
+    sieve_iter(limit).collect()
+}
+
+// primes less than `limit`, in ascending order
+fn sieve_iter(limit: u32) -> impl Iterator<Item = u32> {
+    // the `i`th bit is set if `2i + 1` is a composite number: it doesn't store even numbers
+    let odd_count = limit as usize / 2;
+    let mut composites = vec![0u64; odd_count.div_ceil(64)];
+    let is_composite = |composites: &[u64], i: usize| composites[i / 64] & (1 << (i % 64)) != 0;
+
+    let mut i = 1;
+
+    // (2i + 1)^2 < limit
+    while (2 * i + 1) * (2 * i + 1) < limit as usize {
+        if !is_composite(&composites, i) {
+            let p = 2 * i + 1;
+
+            // `p * p` is the first multiple of `p` that's not marked yet, and only odd multiples are stored
+            let mut j = p * p / 2;
+
+            while j < odd_count {
+                composites[j / 64] |= 1 << (j % 64);
+                j += p;
+            }
+        }
+
+        i += 1;
+    }
+
+    // 1 is not a prime, so it starts from 3
+    let two = if limit > 2 { Some(2) } else { None };
+
+    two.into_iter().chain((1..odd_count).filter(
+        move |i| !is_composite(&composites, *i)
+    ).map(
+        |i| 2 * i as u32 + 1
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UBigInt;
+    use super::{nth_prime_worker, segmented_sieve, sieve};
+
+    #[test]
+    fn nth_prime_test() {
+        assert_eq!(UBigInt::nth_prime(1), UBigInt::from_u32(2));
+        assert_eq!(UBigInt::nth_prime(2), UBigInt::from_u32(3));
+        assert_eq!(UBigInt::nth_prime(6), UBigInt::from_u32(13));
+        assert_eq!(UBigInt::nth_prime(1000), UBigInt::from_u32(7919));
+        assert_eq!(UBigInt::nth_prime(10000), UBigInt::from_u32(104729));
+        assert_eq!(UBigInt::nth_prime(100000), UBigInt::from_u32(1299709));
+
+        let primes = sieve(10000);
+
+        for (i, p) in primes.iter().enumerate().step_by(37) {
+            assert_eq!(UBigInt::nth_prime(i as u64 + 1), UBigInt::from_u32(*p));
+
+            // primes after the sieve
+            assert_eq!(nth_prime_worker(i as u64 + 1, 100), UBigInt::from_u32(*p));
+            assert_eq!(nth_prime_worker(i as u64 + 1, 7), UBigInt::from_u32(*p));
+        }
+    }
+
+    #[test]
+    fn segmented_sieve_test() {
+        let primes = sieve(100_000);
+        let base_primes = sieve(400);
+
+        for (start, end) in [(3, 100), (4, 5), (90, 97), (90, 98), (1000, 1000), (1000, 1001), (65536, 100_000), (99_990, 100_000)] {
+            assert_eq!(
+                segmented_sieve(start, end, &base_primes).collect::<Vec<u64>>(),
+                primes.iter().filter(|p| (start..end).contains(&(**p as u64))).map(|p| *p as u64).collect::<Vec<u64>>(),
+                "{start}..{end}",
+            );
+        }
+
+        // 2^32 + 15 is the smallest prime larger than 2^32
+        let base_primes = sieve(70_000);
+        assert_eq!(segmented_sieve((1 << 32) + 1, (1 << 32) + 16, &base_primes).collect::<Vec<u64>>(), vec![(1 << 32) + 15]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn nth_prime_panic_test() {
+        let _ = UBigInt::nth_prime(0);
+    }
+
+    #[test]
+    fn sieve_test() {
+        assert_eq!(
+            UBigInt::primes_below(30),
+            vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29].into_iter().map(UBigInt::from_u32).collect::<Vec<UBigInt>>(),
+        );
+
+        assert_eq!(sieve(0), Vec::<u32>::new());
+        assert_eq!(sieve(2), Vec::<u32>::new());
+        assert_eq!(sieve(3), vec![2]);
+        assert_eq!(sieve(4), vec![2, 3]);
+        assert_eq!(sieve(29), vec![2, 3, 5, 7, 11, 13, 17, 19, 23]);
+        assert_eq!(sieve(30).len(), 10);
+
+        // pi(10^6)
+        assert_eq!(sieve(1_000_000).len(), 78498);
+        assert_eq!(sieve(1_000_000).last(), Some(&999983));
+
+        let primes = sieve(5000);
+
+        for n in 0..5000 {
+            assert_eq!(primes.binary_search(&n).is_ok(), UBigInt::from_u32(n).is_prime(), "{n}");
+        }
+    }
+}
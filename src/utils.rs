@@ -65,6 +65,26 @@ pub fn are_close(a: &crate::Ratio, b: &crate::Ratio, thres: f64) -> bool {
     1.0 - thres <= diff && diff <= 1.0 + thres
 }
 
+/// A deterministic pseudo-random number for tests: it updates `seed` and returns it.
+#[cfg(test)]
+pub fn rand_u32(seed: &mut u32) -> u32 {
+    *seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+    *seed
+}
+
+/// `rand_u32` with a 64-bit state
+#[cfg(test)]
+pub fn rand_u64(seed: &mut u64) -> u64 {
+    *seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    *seed
+}
+
+/// A deterministic pseudo-random number with `len` limbs. None of the limbs is 0.
+#[cfg(test)]
+pub fn rand_ubi(len: usize, seed: &mut u32) -> crate::UBigInt {
+    crate::UBigInt::from_raw((0..len).map(|_| rand_u32(seed).max(1)).collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::gcd_i32;